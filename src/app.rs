@@ -5,7 +5,7 @@
 //! The TUI application module
 //! ---
 
-use crate::handlers;
+use crate::{handlers, ui};
 pub(crate) use crate::{domain, prelude::*, state, Terminal};
 
 // #[derive(Debug)]
@@ -24,32 +24,70 @@ pub struct App {
 
     /// Application render event
     render: crate::handlers::RenderEventHandler,
+
+    /// Self-contained ui components, passed every action
+    components: ui::components::Components,
 }
 
 impl App {
     /// Construct a new app instance
     pub async fn new(config: crate::config::Config) -> Result<Self> {
         // Construct a default application state
-        let state = state::State::default();
+        let mut state = state::State::default();
+
+        // Pre-fill the login form with the default administration email
+        if let Some(email) = config.backend.default_email.clone() {
+            state.auth.login.set_value(state::auth::EMAIL, email);
+        }
+
+        // Load the login IP addresses seen in previous sessions, starting again
+        // from an empty record rather than failing on an unreadable one
+        state.security.known_ips =
+            match state::KnownIps::load(&config.app.data_directory) {
+                Ok(known_ips) => known_ips,
+                Err(error) => {
+                    tracing::warn!("Ignoring the known login IPs: {}", error);
+                    state::KnownIps::default()
+                }
+            };
+
+        // Keep as many ping round trip times as configured
+        state.backend.latency =
+            state::LatencySamples::new(config.backend.latency_samples);
+
+        // Build the keymap, failing at startup on invalid key bindings
+        let keymap = domain::Keymap::new(&config.keybindings)?;
+
+        // Build the theme, failing at startup on an invalid theme
+        let theme = ui::Theme::new(&config.theme)?;
 
         // Construct action handler
-        let actions = handlers::ActionHandler::default();
+        let actions = handlers::ActionHandler::new(keymap.clone());
 
         // Construct a new tick event handler
-        let tick = handlers::TickEventHandler::init(actions.action_sender.clone());
+        let tick = handlers::TickEventHandler::init(
+            config.app.clone(),
+            actions.action_sender.clone(),
+        );
 
         // // Construct a new render event handler
         let render = handlers::RenderEventHandler::init(
             config.clone(),
+            theme.clone(),
             actions.action_sender.clone(),
         );
 
+        // Construct the ui components and register the action sender
+        let mut components = ui::components::Components::new(&config, &keymap, &theme);
+        components.init(&actions.action_sender)?;
+
         Ok(Self {
             state,
             config,
             actions,
             tick,
             render,
+            components,
         })
     }
 
@@ -59,7 +97,9 @@ impl App {
         // Initiate a new backend terminal
         let tick_rate = self.config.app.tick_rate;
         let frame_rate = self.config.app.frame_rate;
-        let mut terminal = Terminal::new(tick_rate, frame_rate)?;
+        let mut terminal = Terminal::new(tick_rate, frame_rate)?
+            .enable_mouse(self.config.app.enable_mouse)
+            .enable_paste(true);
 
         //-- 2. Enter terminal raw mode
         terminal.enter()?;
@@ -68,7 +108,13 @@ impl App {
         while self.state.app.is_running {
             // Map crossterm events into actions
             self.actions
-                .handle_events(self.state.app.mode.clone(), &mut terminal.events)
+                .handle_events(
+                    self.state.app.mode.clone(),
+                    self.state.navigation.current.has_list(),
+                    &self.state.mouse,
+                    &mut terminal.events,
+                    &mut self.components,
+                )
                 .await?;
 
             // Update the app based on the action
@@ -90,6 +136,40 @@ impl App {
                 tracing::debug!("{action:?}");
             }
 
+            // Refuse actions the operator role is not permitted, before any
+            // RPC is made on their behalf
+            if !self.state.backend.role.is_permitted(&action) {
+                let toast_message = format!(
+                    "{action} requires the {} role",
+                    domain::UserRole::Admin
+                );
+                let toast =
+                    domain::Toast::new(toast_message).kind(domain::ToastKind::Info);
+                self.toast(toast);
+                continue;
+            }
+
+            // Pass the action to each component, queueing any follow up action
+            for component in self.components.iter_mut() {
+                if let Some(follow_up) = component.update(&action)? {
+                    self.actions.action_sender.send(follow_up)?;
+                }
+            }
+
+            // Draw the next frame for anything but the passing of time
+            if !matches!(
+                action,
+                domain::Action::Tick | domain::Action::Render | domain::Action::Nil
+            ) {
+                self.state.app.is_dirty = true;
+            }
+
+            // Show the spinner before a request blocks the event loop
+            let is_request = action.is_request();
+            if is_request {
+                self.show_busy(terminal)?;
+            }
+
             // Match action
             match action {
                 // Terminal initiation event action
@@ -104,38 +184,207 @@ impl App {
                 // Application render action
                 domain::Action::Render => {
                     // Handle render event
-                    self.render.handle_event(&mut self.state, terminal)
+                    self.render.handle_event(
+                        &mut self.state,
+                        &mut self.components,
+                        terminal,
+                    )
+                }
+
+                // Lay the screen out again for the new terminal size straight
+                // away, rather than waiting for the next frame
+                domain::Action::Resize(width, height) => {
+                    let area = ratatui::layout::Rect::new(0, 0, width, height);
+                    terminal.resize(area)?;
+                    self.render.handle_event(
+                        &mut self.state,
+                        &mut self.components,
+                        terminal,
+                    )
                 }
 
                 // Quit tui application
                 domain::Action::Quit => self.state.app.is_running = false,
 
-                // Add toast message to the queue
-                domain::Action::Toast(t) => self.state.toast.queue.push_back(t),
-
-                // Clear (remove) current toast message
-                domain::Action::ClearToast => self.state.toast.current = None,
-
-                // Check authentication backend server online status
-                domain::Action::BackendStatusUpdate => {
-                    self.update_backend_status().await;
-                    let toast_message = format!(
-                        "Backend server is: {:?}",
-                        self.state.backend.status
-                    );
-                    let toast = domain::Toast::new(toast_message);
-                    self.state.toast.queue.push_back(toast);
+                // Run an action the number of times counted, i.e. `5j`
+                domain::Action::Repeat(count, action) => {
+                    for _ in 0..count {
+                        self.dispatch(*action.clone());
+                    }
+                }
+
+                // Hand the terminal to the shell until continued, then redraw
+                domain::Action::Suspend => {
+                    terminal.suspend()?;
+                    self.dispatch(domain::Action::Resume);
                 }
+                domain::Action::Resume => terminal.clear()?,
+
+                // Draw less often while the terminal is out of focus
+                domain::Action::Focus(is_focused) => {
+                    self.render.set_focus(is_focused)
+                }
+
+                // Copy text, i.e. a past notification, to the clipboard
+                domain::Action::Copy(text) => {
+                    terminal.copy_to_clipboard(&text)?;
+                    let toast = domain::Toast::new("Copied to the clipboard")
+                        .kind(domain::ToastKind::Success);
+                    self.toast(toast);
+                }
+
+                // Show the key bindings for where the operator is
+                domain::Action::Help => self.components.help.show(
+                    &self.state.app.mode,
+                    &self.state.navigation.current,
+                    &self.state.backend.role,
+                ),
 
                 domain::Action::AppMode(m) => {
                     self.state.app.mode = m;
                 }
 
-                // Do nothing with all other actions
-                _ => {}
+                // Pass any other action on to the controller that handles it
+                action => self.update_controllers(action, terminal).await,
+            }
+
+            if is_request {
+                self.dispatch(domain::Action::Busy(false));
             }
         }
 
         Ok(())
     }
+
+    /// Tell the components a request has started and draw the frame straight
+    /// away, as no frame is drawn until the request is answered
+    fn show_busy(&mut self, terminal: &mut crate::Terminal) -> Result<()> {
+        for component in self.components.iter_mut() {
+            component.update(&domain::Action::Busy(true))?;
+        }
+        self.render
+            .handle_event(&mut self.state, &mut self.components, terminal);
+
+        Ok(())
+    }
+
+    /// Add an action to the queue, handled in this or the next update
+    pub fn dispatch(&self, action: domain::Action) {
+        if let Err(error) = self.actions.action_sender.send(action) {
+            tracing::error!("Error queueing action: {}", error);
+        }
+    }
+
+    /// Queue a toast message to be shown
+    pub fn toast(&self, toast: domain::Toast) {
+        self.dispatch(domain::Action::Toast(toast));
+    }
+
+    /// Change the application mode, through the queue so components see it
+    pub fn set_mode(&self, mode: domain::AppMode) {
+        self.dispatch(domain::Action::AppMode(mode));
+    }
+
+    /// Set the backend status, queueing the change for components to see and
+    /// raising a toast when the status changes
+    pub fn set_backend_status(&mut self, status: domain::BackendStatus) {
+        self.change_backend_status(status, None);
+    }
+
+    /// Set a failing backend status, keeping the error that caused it
+    pub fn set_backend_error(
+        &mut self,
+        status: domain::BackendStatus,
+        error: impl std::fmt::Display,
+    ) {
+        self.change_backend_status(status, Some(error.to_string()));
+    }
+
+    /// Set the backend status from an RPC error, the backend refusing our
+    /// access token, which is then forgotten, or not being reachable. Other
+    /// errors leave it as it is.
+    pub fn set_backend_rpc_error(&mut self, error: &Error) {
+        match error.rpc_code() {
+            tonic::Code::Unauthenticated => self.expire_session(error.rpc_message()),
+            tonic::Code::Unavailable => self.set_backend_error(
+                domain::BackendStatus::Offline,
+                error.rpc_message(),
+            ),
+            _ => {}
+        }
+    }
+
+    /// Forget an access token the backend refuses or that has expired, sending
+    /// the operator back to the login screen to log in again
+    pub fn expire_session(&mut self, reason: impl std::fmt::Display) {
+        if self.state.backend.access_token.is_none() {
+            return;
+        }
+
+        self.state.log_out();
+        self.dispatch(domain::Action::Session(None, None));
+        self.set_mode(domain::AppMode::Normal);
+        self.set_backend_error(domain::BackendStatus::Unauthorized, reason);
+    }
+
+    /// Record the status, when it changed and the error that caused it
+    fn change_backend_status(
+        &mut self,
+        status: domain::BackendStatus,
+        error: Option<String>,
+    ) {
+        let now = chrono::Local::now();
+        let backend = &mut self.state.backend;
+        if let Some(error) = &error {
+            backend.last_error = Some(error.clone());
+            backend.last_error_at = Some(now);
+        }
+        if backend.status == status {
+            return;
+        }
+        let previous = std::mem::replace(&mut backend.status, status.clone());
+        backend.status_since = now;
+        self.dispatch(domain::Action::BackendStatus(status.clone()));
+
+        //-- Tell the operator of the change
+        let reason = error.map(|error| format!(": {error}")).unwrap_or_default();
+        let toast = match (previous, status) {
+            // Logging in raises its own toast
+            (
+                domain::BackendStatus::Online | domain::BackendStatus::Unauthorized,
+                domain::BackendStatus::LoggedIn,
+            ) => return,
+            // Logging out raises its own toast, as does an expired session
+            (
+                domain::BackendStatus::LoggedIn
+                | domain::BackendStatus::Unauthorized,
+                domain::BackendStatus::Online,
+            ) => return,
+            (_, domain::BackendStatus::Connecting) => return,
+            (_, domain::BackendStatus::Offline) => {
+                domain::Toast::new(format!("Backend server went offline{reason}"))
+                    .kind(domain::ToastKind::Warning)
+            }
+            (_, domain::BackendStatus::Degraded) => {
+                domain::Toast::new(format!("Backend server is degraded{reason}"))
+                    .kind(domain::ToastKind::Warning)
+            }
+            (_, domain::BackendStatus::Unauthorized) => domain::Toast::new(format!(
+                "Backend server refused the access token, log in again{reason}"
+            ))
+            .kind(domain::ToastKind::Error),
+            (_, domain::BackendStatus::Incompatible) => {
+                domain::Toast::new(format!("Backend server is incompatible{reason}"))
+                    .kind(domain::ToastKind::Error)
+            }
+            (domain::BackendStatus::Connecting, status) => {
+                domain::Toast::new(format!("Backend server is: {status}"))
+            }
+            (_, status) => {
+                domain::Toast::new(format!("Backend server is back: {status}"))
+                    .kind(domain::ToastKind::Success)
+            }
+        };
+        self.toast(toast);
+    }
 }
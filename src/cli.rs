@@ -6,6 +6,10 @@
 //!
 //! ---
 
+use core::ops;
+
+use crate::domain;
+
 #[derive(Debug, clap::Parser)]
 #[command(author, version = version(), about)]
 pub struct Args {
@@ -14,7 +18,7 @@ pub struct Args {
     pub config: String,
 }
 
-const VERSION_MESSAGE: &str = concat!(
+pub const VERSION_MESSAGE: &str = concat!(
     env!("CARGO_PKG_VERSION"),
     "-",
     env!("VERGEN_GIT_DESCRIBE"),
@@ -23,6 +27,18 @@ const VERSION_MESSAGE: &str = concat!(
     ")"
 );
 
+/// The git commit the TUI was built from
+pub const GIT_SHA: &str = env!("VERGEN_GIT_SHA");
+
+/// The platform the TUI was built for
+pub const TARGET_TRIPLE: &str = env!("VERGEN_CARGO_TARGET_TRIPLE");
+
+/// The backend versions the TUI speaks the protocol of, from the first
+/// supported up to but not including the first unsupported. Bump this with
+/// the backend proto submodule.
+pub const SUPPORTED_BACKEND: ops::Range<domain::BackendVersion> =
+    domain::BackendVersion::new(0, 1, 0)..domain::BackendVersion::new(1, 0, 0);
+
 pub fn version() -> String {
     let author = clap::crate_authors!();
 
@@ -17,13 +17,27 @@ pub mod rpc {
 
 #[derive(Debug, Clone)]
 pub struct RpcClient {
+    authentication: AuthenticationClient,
     utilities: UtilitiesClient,
+    logins: LoginsClient,
+    sessions: SessionsClient,
 }
 
 /// Convenience type alias for authentication client.
+pub type AuthenticationClient =
+    rpc::authentication_client::AuthenticationClient<transport::Channel>;
+
+/// Convenience type alias for utilities client.
 pub type UtilitiesClient =
     rpc::utilities_client::UtilitiesClient<transport::Channel>;
 
+/// Convenience type alias for logins client.
+pub type LoginsClient = rpc::logins_client::LoginsClient<transport::Channel>;
+
+/// Convenience type alias for sessions client.
+pub type SessionsClient =
+    rpc::sessions_client::SessionsClient<transport::Channel>;
+
 impl RpcClient {
     /// Spawn a new tonic client based on the tonic server
     pub async fn new(
@@ -35,15 +49,54 @@ impl RpcClient {
         let endpoint = transport::Channel::builder(uri);
         let channel: transport::Channel = endpoint.connect().await?;
 
+        let authentication = AuthenticationClient::new(channel.clone());
         let utilities = UtilitiesClient::new(channel.clone());
+        let logins = LoginsClient::new(channel.clone());
+        let sessions = SessionsClient::new(channel.clone());
 
-        let client = RpcClient { utilities };
+        let client = RpcClient {
+            authentication,
+            utilities,
+            logins,
+            sessions,
+        };
 
         Ok(client)
     }
 
+    /// Returns the authentication client.
+    pub fn authentication(&mut self) -> &mut AuthenticationClient {
+        &mut self.authentication
+    }
+
     /// Returns the utilities client.
     pub fn utilities(&mut self) -> &mut UtilitiesClient {
         &mut self.utilities
     }
+
+    /// Returns the logins client.
+    pub fn logins(&mut self) -> &mut LoginsClient {
+        &mut self.logins
+    }
+
+    /// Returns the sessions client.
+    pub fn sessions(&mut self) -> &mut SessionsClient {
+        &mut self.sessions
+    }
+}
+
+/// Wrap a request message in a tonic request with the access token added to
+/// the `authorization` metadata as a bearer token.
+pub fn authorised_request<T>(
+    message: T,
+    access_token: &str,
+) -> Result<tonic::Request<T>> {
+    let mut request = tonic::Request::new(message);
+
+    let bearer_token = format!("Bearer {access_token}");
+    let bearer_token: tonic::metadata::MetadataValue<tonic::metadata::Ascii> =
+        bearer_token.parse()?;
+    request.metadata_mut().insert("authorization", bearer_token);
+
+    Ok(request)
 }
@@ -5,7 +5,7 @@
 //! Application configuration module
 //! ---
 
-use std::path;
+use std::{path, time};
 
 /// Application (TUI) configuration struct
 ///
@@ -27,7 +27,59 @@ pub struct AppConfig {
     /// Frame (redraw) of the application refresh in frames per second i.e. number of frames per second
     pub frame_rate: f64,
 
+    /// Most frames per second drawn while the terminal is out of focus
+    #[serde(default = "default_idle_frame_rate")]
+    pub idle_frame_rate: f64,
+
     pub show_statistics: bool,
+
+    /// Capture mouse events, to click tabs, table rows, the footer and toasts.
+    /// Off by default as it takes over the terminal's own text selection.
+    #[serde(default)]
+    pub enable_mouse: bool,
+
+    /// Seconds between backend status checks while the backend is reachable
+    #[serde(default = "default_status_check_seconds")]
+    pub status_check_seconds: u64,
+
+    /// Seconds between backend status checks while the backend is offline,
+    /// noticing sooner when it comes back
+    #[serde(default = "default_offline_status_check_seconds")]
+    pub offline_status_check_seconds: u64,
+
+    /// Seconds between backend status checks while the backend health panel
+    /// is shown, so its ping round trip times are current
+    #[serde(default = "default_health_check_seconds")]
+    pub health_check_seconds: u64,
+
+    /// Seconds between polls of the logins for new IP addresses
+    #[serde(default = "default_logins_watch_seconds")]
+    pub logins_watch_seconds: u64,
+}
+
+/// Draw once a second while out of focus, enough to see the status change
+fn default_idle_frame_rate() -> f64 {
+    1.0
+}
+
+/// Check the backend status every ten minutes
+fn default_status_check_seconds() -> u64 {
+    10 * 60
+}
+
+/// Check an offline backend every thirty seconds
+fn default_offline_status_check_seconds() -> u64 {
+    30
+}
+
+/// Check the backend status every ten seconds while watching its health
+fn default_health_check_seconds() -> u64 {
+    10
+}
+
+/// Watch the logins every minute
+fn default_logins_watch_seconds() -> u64 {
+    60
 }
 
 impl Default for AppConfig {
@@ -51,14 +103,78 @@ impl Default for AppConfig {
 
         let frame_rate: f64 = 60.0;
 
+        let idle_frame_rate = default_idle_frame_rate();
+
         let show_statistics = false;
 
+        let enable_mouse = false;
+
+        let status_check_seconds = default_status_check_seconds();
+        let offline_status_check_seconds = default_offline_status_check_seconds();
+        let health_check_seconds = default_health_check_seconds();
+        let logins_watch_seconds = default_logins_watch_seconds();
+
         Self {
             data_directory,
             config_file,
             tick_rate,
             frame_rate,
-            show_statistics
+            idle_frame_rate,
+            show_statistics,
+            enable_mouse,
+            status_check_seconds,
+            offline_status_check_seconds,
+            health_check_seconds,
+            logins_watch_seconds,
+        }
+    }
+}
+
+impl AppConfig {
+    /// How long to wait between backend status checks, sooner while offline
+    /// or while the backend health panel is shown
+    pub fn status_check_interval(
+        &self,
+        is_offline: bool,
+        is_health_shown: bool,
+    ) -> time::Duration {
+        let mut seconds = if is_offline {
+            self.offline_status_check_seconds
+        } else {
+            self.status_check_seconds
+        };
+        if is_health_shown {
+            seconds = seconds.min(self.health_check_seconds);
         }
+
+        time::Duration::from_secs(seconds)
+    }
+
+    /// How long to wait between polls of the logins
+    pub fn logins_watch_interval(&self) -> time::Duration {
+        time::Duration::from_secs(self.logins_watch_seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn the_backend_is_pinged_more_often_while_its_health_is_shown() {
+        //-- Setup and Fixtures (Arrange)
+        let config = AppConfig::default();
+        let seconds = time::Duration::from_secs;
+
+        //-- Execute Function (Act)
+        let hidden = config.status_check_interval(false, false);
+        let shown = config.status_check_interval(false, true);
+
+        //-- Checks (Assertions)
+        assert_eq!(hidden, seconds(10 * 60));
+        assert_eq!(shown, seconds(10));
+        assert_eq!(config.status_check_interval(true, false), seconds(30));
+        assert_eq!(config.status_check_interval(true, true), seconds(10));
     }
 }
@@ -5,7 +5,7 @@
 //! Authentication backend configuration module
 //! ---
 
-use std::net;
+use std::{net, time};
 
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct BackendConfig {
@@ -17,6 +17,15 @@ pub struct BackendConfig {
 
     /// Default email address for administration login
     pub default_email: Option<String>,
+
+    /// Flag the backend as degraded once a ping takes longer than this, in
+    /// milliseconds
+    #[serde(default = "default_degraded_latency_ms")]
+    pub degraded_latency_ms: u64,
+
+    /// The most ping round trip times kept for the backend health panel
+    #[serde(default = "default_latency_samples")]
+    pub latency_samples: usize,
 }
 
 impl Default for BackendConfig {
@@ -29,14 +38,33 @@ impl Default for BackendConfig {
             ip: localhost,
             port,
             default_email,
+            degraded_latency_ms: default_degraded_latency_ms(),
+            latency_samples: default_latency_samples(),
         }
     }
 }
 
+/// Half a second is slow for a ping on the networks the backend runs on
+fn default_degraded_latency_ms() -> u64 {
+    500
+}
+
+/// Ten minutes of pings while the health panel is shown, as the backend is
+/// pinged every ten seconds then. Otherwise it is pinged every ten minutes, so
+/// the samples go back ten hours.
+fn default_latency_samples() -> usize {
+    60
+}
+
 impl BackendConfig {
     pub fn address(&self) -> net::SocketAddr {
         net::SocketAddr::new(self.ip, self.port)
     }
+
+    /// The ping round trip time the backend is flagged as degraded over
+    pub fn degraded_latency(&self) -> time::Duration {
+        time::Duration::from_millis(self.degraded_latency_ms)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,145 @@
+//-- ./src/config/keybindings.rs
+
+// #![allow(unused)] // For development only
+
+//! Key bindings configuration module
+//!
+//! Each application mode maps key chords to action names, e.g.
+//!
+//! ```toml
+//! [keybindings.normal]
+//! "ctrl-p" = "OpenCommandPalette"
+//! "g h" = "Navigate(Home)"
+//! ```
+//!
+//! A chord is a key name with optional `ctrl-`, `alt-` and `shift-` modifiers,
+//! and several chords separated by spaces make a key sequence. A mode section
+//! in the config file replaces the default bindings for that mode.
+//!
+//! In normal mode on screens with a list, digits are a count for the next
+//! list action, i.e. `5j`, so `alt-1` to `alt-9` jump to a tab there. An
+//! incomplete key sequence or count is dropped after `sequence_timeout_ms`.
+//! ---
+
+use std::collections::BTreeMap;
+
+/// Key bindings configuration struct, key chords to action names
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct KeybindingsConfig {
+    /// Key bindings used in normal mode
+    #[serde(default = "default_normal")]
+    pub normal: BTreeMap<String, String>,
+
+    /// Key bindings used in input mode, keys without a binding are typed into
+    /// the form field in focus
+    #[serde(default = "default_input")]
+    pub input: BTreeMap<String, String>,
+
+    /// How long to wait for the next key of a sequence or count
+    #[serde(default = "default_sequence_timeout_ms")]
+    pub sequence_timeout_ms: u64,
+}
+
+impl Default for KeybindingsConfig {
+    fn default() -> Self {
+        Self {
+            normal: default_normal(),
+            input: default_input(),
+            sequence_timeout_ms: default_sequence_timeout_ms(),
+        }
+    }
+}
+
+/// Wait a second for the rest of a key sequence, as vim does
+fn default_sequence_timeout_ms() -> u64 {
+    1000
+}
+
+/// Collect key chord and action name pairs into a binding map
+fn bindings(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(keys, action)| (keys.to_string(), action.to_string()))
+        .collect()
+}
+
+/// Default normal mode key bindings
+fn default_normal() -> BTreeMap<String, String> {
+    let mut normal = bindings(&[
+        ("q", "Quit"),
+        ("ctrl-z", "Suspend"),
+        ("?", "Help"),
+        ("f1", "Help"),
+        (":", "OpenCommandPalette"),
+        ("ctrl-p", "OpenCommandPalette"),
+        ("ctrl-n", "Notifications"),
+        ("u", "BackendStatusUpdate"),
+        ("O", "Logout"),
+        ("<", "AuditLogPreviousDay"),
+        (">", "AuditLogNextDay"),
+        ("alt-left", "NavigateBack"),
+        ("backspace", "NavigateBack"),
+        ("H", "NavigateBack"),
+        ("alt-right", "NavigateForward"),
+        ("L", "NavigateForward"),
+        ("l", "Navigate(Login)"),
+        ("r", "Navigate(Register)"),
+        ("f", "Navigate(ForgotPassword)"),
+        ("t", "Navigate(ResetPassword)"),
+        ("h", "Navigate(Home)"),
+        ("i", "Navigate(Logins)"),
+        ("s", "Navigate(Security)"),
+        ("a", "Navigate(AuditLog)"),
+        ("v", "Navigate(About)"),
+        ("down", "ListNext"),
+        ("up", "ListPrevious"),
+        ("j", "ListNext"),
+        ("k", "ListPrevious"),
+        ("g g", "ListFirst"),
+        ("home", "ListFirst"),
+        ("G", "ListLast"),
+        ("end", "ListLast"),
+        ("ctrl-d", "ListPageDown"),
+        ("pagedown", "ListPageDown"),
+        ("ctrl-u", "ListPageUp"),
+        ("pageup", "ListPageUp"),
+        ("/", "Search"),
+        ("n", "SearchNext"),
+        ("N", "SearchPrevious"),
+        ("tab", "SplitFocus"),
+        ("}", "SplitGrow"),
+        ("{", "SplitShrink"),
+        ("|", "SplitFlip"),
+        ("m", "AppMode(Input)"),
+        ("e", "FormEdit"),
+        ("esc", "ClearToast"),
+        ("enter", "ToastFollowUp"),
+    ]);
+
+    // Jump to the numbered tab, the action counts from zero. Bare digits are
+    // a count on screens with a list, so the alt digits work on every screen.
+    for tab in 1..=9 {
+        let action = format!("NavigateTab({})", tab - 1);
+        normal.insert(format!("alt-{tab}"), action.clone());
+        normal.insert(tab.to_string(), action);
+    }
+
+    normal
+}
+
+/// Default input mode key bindings
+fn default_input() -> BTreeMap<String, String> {
+    bindings(&[
+        ("esc", "AppMode(Normal)"),
+        ("f1", "Help"),
+        ("ctrl-p", "OpenCommandPalette"),
+        ("ctrl-n", "Notifications"),
+        ("tab", "FormNextField"),
+        ("down", "FormNextField"),
+        ("backtab", "FormPreviousField"),
+        ("up", "FormPreviousField"),
+        ("enter", "FormSubmit"),
+        ("ctrl-e", "FormEdit"),
+        ("ctrl-z", "Suspend"),
+    ])
+}
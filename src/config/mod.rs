@@ -15,7 +15,15 @@ use crate::cli;
 use crate::prelude::*;
 
 mod app;
+pub use app::AppConfig;
 mod backend;
+pub use backend::BackendConfig;
+mod keybindings;
+pub use keybindings::KeybindingsConfig;
+mod theme;
+mod toast;
+pub use toast::ToastConfig;
+pub use theme::{StyleConfig, ThemeConfig, ThemeFile};
 
 #[derive(Debug, Clone, PartialEq, Default, serde::Deserialize, serde::Serialize)]
 pub struct Config {
@@ -24,6 +32,18 @@ pub struct Config {
 
     /// Backend server configuration
     pub backend: backend::BackendConfig,
+
+    /// Key bindings for each application mode
+    #[serde(default)]
+    pub keybindings: keybindings::KeybindingsConfig,
+
+    /// Toast message durations and stacking
+    #[serde(default)]
+    pub toast: toast::ToastConfig,
+
+    /// Built-in theme, theme file and named style overrides
+    #[serde(default)]
+    pub theme: theme::ThemeConfig,
 }
 
 impl Config {
@@ -0,0 +1,79 @@
+//-- ./src/config/theme.rs
+
+// #![allow(unused)] // For development only
+
+//! Theme configuration module
+//!
+//! Pick a built-in theme by name, optionally load a custom theme file and
+//! override individual named styles, e.g.
+//!
+//! ```toml
+//! [theme]
+//! name = "dark"
+//!
+//! [theme.styles."toast.error"]
+//! fg = "white"
+//! bg = "red"
+//! modifiers = ["bold"]
+//! ```
+//!
+//! A theme file uses the same `base` name and `styles` table.
+//! ---
+
+use std::{collections::BTreeMap, path};
+
+/// A named style, colours are ratatui colour names, indexes or `#rrggbb`
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize, serde::Serialize)]
+pub struct StyleConfig {
+    /// Foreground colour
+    pub fg: Option<String>,
+
+    /// Background colour
+    pub bg: Option<String>,
+
+    /// Text modifiers, i.e. `bold`, `dim`, `reversed`
+    #[serde(default)]
+    pub modifiers: Vec<String>,
+}
+
+/// Theme configuration struct
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ThemeConfig {
+    /// Built-in theme to start from: `dark`, `light` or `high-contrast`
+    #[serde(default = "default_name")]
+    pub name: String,
+
+    /// Custom theme file, applied over the built-in theme
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<path::PathBuf>,
+
+    /// Named styles overriding the theme
+    #[serde(default)]
+    pub styles: BTreeMap<String, StyleConfig>,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self {
+            name: default_name(),
+            file: None,
+            styles: BTreeMap::new(),
+        }
+    }
+}
+
+/// The built-in theme used when none is configured
+fn default_name() -> String {
+    "dark".to_string()
+}
+
+/// A custom theme file
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+pub struct ThemeFile {
+    /// Built-in theme the file starts from, replacing the configured name
+    pub base: Option<String>,
+
+    /// Named styles set by the file
+    #[serde(default)]
+    pub styles: BTreeMap<String, StyleConfig>,
+}
@@ -0,0 +1,67 @@
+//-- ./src/config/toast.rs
+
+// #![allow(unused)] // For development only
+
+//! Toast message configuration module
+//! ---
+
+use std::time;
+
+use crate::domain;
+
+/// Toast message configuration struct. Durations are in seconds, a duration of
+/// `0` keeps the toast shown until it is dismissed.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct ToastConfig {
+    /// The most toasts stacked in the top right corner at once
+    pub max_visible: usize,
+
+    /// The most past toast messages kept in the notification history
+    pub history_size: usize,
+
+    /// How long error toasts are shown for
+    pub error_seconds: u64,
+
+    /// How long warning toasts are shown for
+    pub warning_seconds: u64,
+
+    /// How long info toasts are shown for
+    pub info_seconds: u64,
+
+    /// How long success toasts are shown for
+    pub success_seconds: u64,
+
+    /// How long notification toasts are shown for
+    pub notification_seconds: u64,
+}
+
+impl Default for ToastConfig {
+    fn default() -> Self {
+        Self {
+            max_visible: 3,
+            history_size: 200,
+            error_seconds: 0,
+            warning_seconds: 8,
+            info_seconds: 3,
+            success_seconds: 3,
+            notification_seconds: 3,
+        }
+    }
+}
+
+impl ToastConfig {
+    /// How long a toast of the kind is shown for, `None` if it is shown until
+    /// dismissed
+    pub fn duration(&self, kind: &domain::ToastKind) -> Option<time::Duration> {
+        let seconds = match kind {
+            domain::ToastKind::Error => self.error_seconds,
+            domain::ToastKind::Warning => self.warning_seconds,
+            domain::ToastKind::Info => self.info_seconds,
+            domain::ToastKind::Success => self.success_seconds,
+            domain::ToastKind::Notification => self.notification_seconds,
+        };
+
+        (seconds > 0).then(|| time::Duration::from_secs(seconds))
+    }
+}
@@ -0,0 +1,74 @@
+//-- ./src/controllers/audit_log.rs
+
+//! Record administrative and self-service actions in the local audit log and
+//! load the audit entries for the audit log viewer
+
+use crate::{domain, state};
+
+impl crate::App {
+    /// Handle the audit log viewer actions, handing back any other action
+    pub fn update_audit_log(
+        &mut self,
+        action: domain::Action,
+    ) -> Option<domain::Action> {
+        match action {
+            // Step the audit log viewer through the days
+            domain::Action::AuditLogPreviousDay => self.load_audit_day(-1),
+            domain::Action::AuditLogNextDay => self.load_audit_day(1),
+            action => return Some(action),
+        }
+
+        None
+    }
+
+    /// Append an action to the audit log in the data directory.
+    ///
+    /// Every mutation made through the TUI should be recorded here, with the
+    /// RPC status returned by the backend. Self-service actions are recorded
+    /// with no operator.
+    pub fn audit(&mut self, entry: domain::AuditEntry) {
+        let data_directory = &self.config.app.data_directory;
+
+        if let Err(error) = state::audit::append(data_directory, &entry) {
+            tracing::error!("Error writing audit log entry: {}", error);
+            let toast = domain::Toast::new("Unable to write audit log entry")
+                .kind(domain::ToastKind::Error);
+            self.toast(toast);
+        }
+
+        // Keep the viewer current if it is showing today
+        let today = chrono::Local::now().date_naive();
+        if self.state.audit.day == today {
+            self.state.audit.entries.push(entry);
+        }
+    }
+
+    /// The email address to record as the operator in the audit log
+    pub fn operator_email(&self) -> String {
+        self.state
+            .backend
+            .operator_email
+            .clone()
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+
+    /// Load the audit log entries for a day offset from the day being viewed
+    pub fn load_audit_day(&mut self, offset_days: i64) {
+        let day = self.state.audit.day + chrono::Duration::days(offset_days);
+        let data_directory = &self.config.app.data_directory;
+
+        match state::audit::load_day(data_directory, day) {
+            Ok(entries) => {
+                self.state.audit.day = day;
+                self.state.audit.entries = entries;
+                self.state.audit.table.select(None);
+            }
+            Err(error) => {
+                tracing::error!("Error reading audit log: {}", error);
+                let toast = domain::Toast::new("Unable to read audit log")
+                    .kind(domain::ToastKind::Error);
+                self.toast(toast);
+            }
+        }
+    }
+}
@@ -0,0 +1,347 @@
+//-- ./src/controllers/authentication.rs
+
+//! Submit the authentication forms shown when not logged in.
+//!
+//! Forms are validated inline before any RPC is made, so the operator can see
+//! which field needs fixing
+
+use crate::{
+    client, domain, services,
+    state::{auth, form::FormState},
+};
+
+/// Minimum password length accepted by the backend
+const MINIMUM_PASSWORD_LENGTH: usize = 8;
+
+impl crate::App {
+    /// Handle the session actions, handing back any other action
+    pub fn update_authentication(
+        &mut self,
+        action: domain::Action,
+    ) -> Option<domain::Action> {
+        match action {
+            // Requests made with an expired token will be refused
+            domain::Action::TokenExpired => {
+                self.expire_session("access token expired")
+            }
+
+            // Forget the tokens and return to the login screen
+            domain::Action::Logout => self.logout(),
+
+            action => return Some(action),
+        }
+
+        None
+    }
+
+    /// Submit the form on the authentication page being shown
+    pub async fn submit_form(&mut self) {
+        if self.state.backend.access_token.is_some() {
+            return;
+        }
+
+        match self.state.navigation.current {
+            domain::Screen::Login => self.login().await,
+            domain::Screen::Register => self.register().await,
+            domain::Screen::ForgotPassword => self.request_password_reset().await,
+            domain::Screen::ResetPassword => self.reset_password().await,
+            _ => {}
+        }
+    }
+
+    /// Log in to the backend with the login form email and password
+    async fn login(&mut self) {
+        //-- 1. Validate the login form
+        let form = &mut self.state.auth.login;
+        form.clear_errors();
+        let email = form.value(auth::EMAIL).trim().to_string();
+        let password = form.value(auth::PASSWORD);
+
+        if let Some(error) = validate_email(&email) {
+            form.set_error(auth::EMAIL, error);
+        }
+        if password.is_empty() {
+            form.set_error(auth::PASSWORD, "Password is required");
+        }
+        if form.has_errors() {
+            return;
+        }
+
+        //-- 2. Make the login request
+        let Some(mut authentication_service) = self.authentication_service().await
+        else {
+            return;
+        };
+        let response = authentication_service.login(&email, &password).await;
+
+        //-- 3. Update the state with the response
+        self.state.auth.login.clear_secrets();
+        match response {
+            Ok((access_token, refresh_token)) => {
+                self.state.backend.set_tokens(access_token, refresh_token);
+                self.state.backend.operator_email = Some(email.clone());
+                self.dispatch(domain::Action::Session(
+                    Some(email.clone()),
+                    self.state.backend.access_token_expires_at,
+                ));
+                self.set_backend_status(domain::BackendStatus::LoggedIn);
+                self.state.auth.login.notice = None;
+                self.state.navigation.reset(domain::Screen::Home);
+                self.set_mode(domain::AppMode::Normal);
+
+                let toast_message = format!("Logged in as {email}");
+                let toast = domain::Toast::new(toast_message)
+                    .kind(domain::ToastKind::Success);
+                self.toast(toast);
+            }
+            Err(error) => {
+                tracing::error!("Error logging in: {}", error);
+                self.state.auth.login.notice =
+                    Some(format!("Login failed: {}", error.rpc_message()));
+            }
+        }
+    }
+
+    /// Register a new account with the registration form fields
+    async fn register(&mut self) {
+        //-- 1. Validate the registration form
+        let form = &mut self.state.auth.register;
+        form.clear_errors();
+        let email = form.value(auth::EMAIL).trim().to_string();
+        let name = form.value(auth::NAME).trim().to_string();
+        let password = form.value(auth::PASSWORD);
+        let confirm_password = form.value(auth::CONFIRM_PASSWORD);
+
+        if let Some(error) = validate_email(&email) {
+            form.set_error(auth::EMAIL, error);
+        }
+        if name.is_empty() {
+            form.set_error(auth::NAME, "Name is required");
+        }
+        validate_new_password(form, &password, &confirm_password);
+        if form.has_errors() {
+            return;
+        }
+
+        //-- 2. Make the registration request
+        let Some(mut authentication_service) = self.authentication_service().await
+        else {
+            return;
+        };
+        let response = authentication_service
+            .register(&email, &name, &password)
+            .await;
+
+        //-- 3. Record the self-service registration in the audit log
+        let status = match &response {
+            Ok(_) => tonic::Code::Ok,
+            Err(error) => error.rpc_code(),
+        };
+        let entry = domain::AuditEntry::self_service(
+            domain::AuditAction::SelfRegister,
+            email.clone(),
+        )
+        .after(serde_json::json!({ "email": email, "name": name }))
+        .status(status);
+        self.audit(entry);
+
+        //-- 4. Update the state with the response
+        self.state.auth.register.clear_secrets();
+        match response {
+            Ok(()) => {
+                // Send the operator back to login, ready for the verified account
+                self.state.auth.register = auth::AuthState::default().register;
+                self.state.auth.login.set_value(auth::EMAIL, email.clone());
+                self.state.auth.login.notice = Some(format!(
+                    "Registered {email}, check your email to verify the account"
+                ));
+                self.state.navigation.navigate(domain::Screen::Login);
+                self.set_mode(domain::AppMode::Normal);
+            }
+            Err(error) => {
+                tracing::error!("Error registering account: {}", error);
+                self.state.auth.register.notice =
+                    Some(format!("Registration failed: {}", error.rpc_message()));
+            }
+        }
+    }
+
+    /// Request a password reset token be emailed to the forgot password form
+    /// email address
+    async fn request_password_reset(&mut self) {
+        //-- 1. Validate the forgot password form
+        let form = &mut self.state.auth.forgot_password;
+        form.clear_errors();
+        let email = form.value(auth::EMAIL).trim().to_string();
+
+        if let Some(error) = validate_email(&email) {
+            form.set_error(auth::EMAIL, error);
+        }
+        if form.has_errors() {
+            return;
+        }
+
+        //-- 2. Make the password reset request
+        let Some(mut authentication_service) = self.authentication_service().await
+        else {
+            return;
+        };
+        let response = authentication_service.request_password_reset(&email).await;
+
+        //-- 3. Update the state with the response
+        match response {
+            Ok(()) => {
+                // Move on to entering the emailed token
+                self.state.auth.forgot_password.notice = None;
+                self.state.auth.reset_password.notice = Some(format!(
+                    "Check {email} for a reset token and paste it below"
+                ));
+                self.state
+                    .navigation
+                    .navigate(domain::Screen::ResetPassword);
+            }
+            Err(error) => {
+                tracing::error!("Error requesting password reset: {}", error);
+                self.state.auth.forgot_password.notice =
+                    Some(format!("Reset request failed: {}", error.rpc_message()));
+            }
+        }
+    }
+
+    /// Set a new password with the reset token pasted into the reset password
+    /// form
+    async fn reset_password(&mut self) {
+        //-- 1. Validate the reset password form
+        let form = &mut self.state.auth.reset_password;
+        form.clear_errors();
+        let reset_token = form.value(auth::RESET_TOKEN).trim().to_string();
+        let password = form.value(auth::PASSWORD);
+        let confirm_password = form.value(auth::CONFIRM_PASSWORD);
+
+        if reset_token.is_empty() {
+            form.set_error(auth::RESET_TOKEN, "Paste the emailed reset token");
+        }
+        validate_new_password(form, &password, &confirm_password);
+        if form.has_errors() {
+            return;
+        }
+
+        //-- 2. Make the reset password request
+        let Some(mut authentication_service) = self.authentication_service().await
+        else {
+            return;
+        };
+        let response = authentication_service
+            .reset_password(&reset_token, &password)
+            .await;
+
+        //-- 3. Record the self-service password reset in the audit log, never
+        // the token or password. The token is issued for the user whose
+        // password it resets, so take the target from its subject, the user id.
+        // The reset form has no email, it is typed into another form, if at all.
+        let target = domain::TokenClaims::decode(&reset_token)
+            .ok()
+            .and_then(|claims| claims.sub)
+            .unwrap_or_else(|| "unknown user".to_string());
+        let status = match &response {
+            Ok(_) => tonic::Code::Ok,
+            Err(error) => error.rpc_code(),
+        };
+        let entry = domain::AuditEntry::self_service(
+            domain::AuditAction::SelfResetPassword,
+            target,
+        )
+        .status(status);
+        self.audit(entry);
+
+        //-- 4. Update the state with the response
+        self.state.auth.reset_password.clear_secrets();
+        match response {
+            Ok(()) => {
+                // Send the operator back to login with the new password
+                self.state.auth.reset_password =
+                    auth::AuthState::default().reset_password;
+                self.state.auth.login.notice =
+                    Some("Password reset, login with the new password".to_string());
+                let email = self.state.auth.forgot_password.value(auth::EMAIL);
+                if !email.trim().is_empty() {
+                    self.state.auth.login.set_value(auth::EMAIL, email.trim());
+                }
+                self.state.navigation.navigate(domain::Screen::Login);
+                self.set_mode(domain::AppMode::Normal);
+            }
+            Err(error) => {
+                tracing::error!("Error resetting password: {}", error);
+                self.state.auth.reset_password.notice =
+                    Some(format!("Password reset failed: {}", error.rpc_message()));
+            }
+        }
+    }
+
+    /// Log out, forgetting the tokens and operator and showing the login screen
+    pub fn logout(&mut self) {
+        if self.state.backend.access_token.is_none() {
+            let toast =
+                domain::Toast::new("Not logged in").kind(domain::ToastKind::Info);
+            self.toast(toast);
+            return;
+        }
+
+        self.state.log_out();
+        self.dispatch(domain::Action::Session(None, None));
+        self.set_mode(domain::AppMode::Normal);
+        if self.state.backend.status == domain::BackendStatus::LoggedIn {
+            self.set_backend_status(domain::BackendStatus::Online);
+        }
+
+        let toast =
+            domain::Toast::new("Logged out").kind(domain::ToastKind::Success);
+        self.toast(toast);
+    }
+
+    /// Build an authentication service, showing an error toast if the backend
+    /// can not be reached
+    async fn authentication_service(
+        &mut self,
+    ) -> Option<services::AuthenticationService> {
+        let rpc_server_address = self.config.backend.address();
+
+        match client::RpcClient::new(rpc_server_address).await {
+            Ok(rpc_client) => Some(services::AuthenticationService::new(rpc_client)),
+            Err(error) => {
+                tracing::error!("Error connecting to backend server: {}", error);
+                self.set_backend_error(domain::BackendStatus::Offline, error);
+                let toast = domain::Toast::new("Backend server is offline")
+                    .kind(domain::ToastKind::Error)
+                    .follow_up("Retry", domain::Action::FormSubmit);
+                self.toast(toast);
+                None
+            }
+        }
+    }
+}
+
+/// Check an email address looks valid, returning the inline error if not
+fn validate_email(email: &str) -> Option<&'static str> {
+    let Some((local, domain)) = email.split_once('@') else {
+        return Some("Email address needs an @");
+    };
+
+    if local.is_empty() || !domain.contains('.') || domain.ends_with('.') {
+        return Some("Email address is not valid");
+    }
+
+    None
+}
+
+/// Check a new password is long enough and confirmed, setting the inline
+/// errors on the form if not
+fn validate_new_password(form: &mut FormState, password: &str, confirm: &str) {
+    if password.chars().count() < MINIMUM_PASSWORD_LENGTH {
+        let error =
+            format!("Password needs at least {MINIMUM_PASSWORD_LENGTH} characters");
+        form.set_error(auth::PASSWORD, error);
+    } else if password != confirm {
+        form.set_error(auth::CONFIRM_PASSWORD, "Passwords do not match");
+    }
+}
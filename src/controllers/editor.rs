@@ -0,0 +1,154 @@
+//-- ./src/controllers/editor.rs
+
+//! Edit the form field in focus in the operator's own editor, handing the
+//! terminal over to `$VISUAL` or `$EDITOR` until it exits
+
+use std::{env, fs, io, io::Write, path, process, time};
+
+use crate::{domain, prelude::*};
+
+/// The editor used when neither `$VISUAL` nor `$EDITOR` is set
+const DEFAULT_EDITOR: &str = "vi";
+
+impl crate::App {
+    /// Edit the value of the form field in focus in an external editor.
+    ///
+    /// Failures, i.e. an editor that is not installed, are shown in an error
+    /// toast rather than ending the session.
+    pub fn edit_in_editor(&mut self, terminal: &mut crate::Terminal) {
+        //-- 1. Find the field in focus, never writing secrets to a file
+        let Some(field) = self
+            .state
+            .active_form_mut()
+            .and_then(|form| form.focused())
+            .cloned()
+        else {
+            return;
+        };
+        if field.is_secret {
+            let toast = domain::Toast::new(format!(
+                "{} can not be edited in an editor",
+                field.label
+            ))
+            .kind(domain::ToastKind::Info);
+            self.toast(toast);
+            return;
+        }
+        let editor = env::var("VISUAL")
+            .or_else(|_| env::var("EDITOR"))
+            .unwrap_or_else(|_| DEFAULT_EDITOR.to_string());
+
+        //-- 2. Write the value to a new temporary file only we can read
+        let path = temporary_path();
+        if let Err(error) = write_private(&path, field.input.value()) {
+            self.editor_failed(&editor, error.into());
+            return;
+        }
+
+        //-- 3. Run the editor, reading the edited value back
+        let edited = run_editor(terminal, &editor, &path);
+        self.dispatch(domain::Action::Resume);
+
+        //-- 4. Always remove the file, which the editor may have removed already
+        if let Err(error) = fs::remove_file(&path) {
+            if error.kind() != io::ErrorKind::NotFound {
+                tracing::error!("Error removing {}: {}", path.display(), error);
+            }
+        }
+
+        match edited {
+            Ok(text) => {
+                if let Some(form) = self.state.active_form_mut() {
+                    form.replace_focused(text.trim_end());
+                }
+            }
+            Err(error) => self.editor_failed(&editor, error),
+        }
+    }
+
+    /// Log an editor failure and show it in an error toast
+    fn editor_failed(&mut self, editor: &str, error: Error) {
+        tracing::error!("Error running editor: {}", error);
+        let toast = domain::Toast::new(format!("Unable to run {editor}: {error}"))
+            .kind(domain::ToastKind::Error);
+        self.toast(toast);
+    }
+}
+
+/// A temporary file path unique to this process and edit
+fn temporary_path() -> path::PathBuf {
+    let nanos = time::SystemTime::now()
+        .duration_since(time::UNIX_EPOCH)
+        .map(|since| since.subsec_nanos())
+        .unwrap_or_default();
+
+    env::temp_dir().join(format!(
+        "authentication_tui-{}-{nanos}.txt",
+        process::id()
+    ))
+}
+
+/// Write the text to a new file readable only by us, failing rather than
+/// following a file or link that is already there
+fn write_private(path: &path::Path, text: &str) -> io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    options.open(path)?.write_all(text.as_bytes())
+}
+
+/// Run the editor on the file, which may have arguments, i.e. `code --wait`,
+/// returning the edited text unless the editor failed
+fn run_editor(
+    terminal: &mut crate::Terminal,
+    editor: &str,
+    path: &path::Path,
+) -> Result<String> {
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or(DEFAULT_EDITOR);
+    let status = terminal.hand_over(|| {
+        process::Command::new(program)
+            .args(words)
+            .arg(path)
+            .status()
+    })??;
+
+    if !status.success() {
+        return Err(io::Error::other(format!("{editor} {status}")).into());
+    }
+
+    Ok(fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn field_values_are_written_to_new_private_files() {
+        //-- Setup and Fixtures (Arrange)
+        let path = temporary_path();
+
+        //-- Execute Function (Act)
+        let written = write_private(&path, "user@example.com");
+        let rewritten = write_private(&path, "other@example.com");
+
+        //-- Checks (Assertions)
+        assert!(written.is_ok());
+        assert_eq!(rewritten.unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "user@example.com");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        fs::remove_file(&path).unwrap();
+    }
+}
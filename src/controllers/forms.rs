@@ -0,0 +1,53 @@
+//-- ./src/controllers/forms.rs
+
+//! Edit and submit the form on the screen being shown
+//! ---
+
+use crate::domain;
+
+impl crate::App {
+    /// Handle the form actions, handing back any other action
+    pub async fn update_forms(
+        &mut self,
+        action: domain::Action,
+        terminal: &mut crate::Terminal,
+    ) -> Option<domain::Action> {
+        match action {
+            // Edit the form being shown
+            domain::Action::FormInput(key_event) => {
+                if let Some(form) = self.state.active_form_mut() {
+                    form.handle_key(key_event);
+                }
+            }
+            domain::Action::FormNextField => {
+                if let Some(form) = self.state.active_form_mut() {
+                    form.focus_next();
+                }
+            }
+            domain::Action::FormPreviousField => {
+                if let Some(form) = self.state.active_form_mut() {
+                    form.focus_previous();
+                }
+            }
+
+            // Paste into the form field in focus, switching to input mode so a
+            // pasted token can be edited straight away
+            domain::Action::Paste(text) => {
+                if let Some(form) = self.state.active_form_mut() {
+                    form.paste(&text);
+                    self.set_mode(domain::AppMode::Input);
+                }
+            }
+
+            // Edit the form field in focus in an external editor
+            domain::Action::FormEdit => self.edit_in_editor(terminal),
+
+            // Submit the form being shown
+            domain::Action::FormSubmit => self.submit_form().await,
+
+            action => return Some(action),
+        }
+
+        None
+    }
+}
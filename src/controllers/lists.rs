@@ -0,0 +1,170 @@
+//-- ./src/controllers/lists.rs
+
+//! Move the row selection of the table on the screen being shown, and search
+//! its rows. While the detail pane has focus the same keys scroll the detail
+//! of the selected row instead.
+
+use crate::{domain, state};
+
+/// Rows moved by a page up or down
+const PAGE_ROWS: isize = 10;
+
+impl crate::App {
+    /// Handle the list, search and split pane actions, handing back any other
+    /// action
+    pub fn update_lists(
+        &mut self,
+        action: domain::Action,
+    ) -> Option<domain::Action> {
+        match action {
+            // Move the row selection of the table being shown
+            domain::Action::ListNext => self.move_selection(1),
+            domain::Action::ListPrevious => self.move_selection(-1),
+            domain::Action::ListSelect(row) => self.focus_row(row),
+            domain::Action::ListFirst => self.select_row(0),
+            domain::Action::ListLast => self.select_last_row(),
+            domain::Action::ListPageDown => self.move_pages(1),
+            domain::Action::ListPageUp => self.move_pages(-1),
+
+            // Search the rows of the table being shown
+            domain::Action::Search => self.open_search(),
+            domain::Action::SearchFor(query) => self.search(query),
+            domain::Action::SearchNext => self.search_step(1),
+            domain::Action::SearchPrevious => self.search_step(-1),
+
+            // Arrange the list and the detail of its selected row, on screens
+            // showing one
+            domain::Action::SplitFocus
+            | domain::Action::SplitGrow
+            | domain::Action::SplitShrink
+            | domain::Action::SplitFlip
+                if !self.state.navigation.current.has_detail() => {}
+            domain::Action::SplitFocus => self.state.split.toggle_focus(),
+            domain::Action::SplitGrow => self.state.split.resize(1),
+            domain::Action::SplitShrink => self.state.split.resize(-1),
+            domain::Action::SplitFlip => {
+                self.state.split.is_flipped = !self.state.split.is_flipped
+            }
+
+            action => return Some(action),
+        }
+
+        None
+    }
+
+    /// Select a row of the table being shown, clamped to the last row
+    pub fn select_row(&mut self, row: usize) {
+        // Scroll the detail to the line, clamped to the end as it is rendered
+        if self.state.is_detail_focused() {
+            self.state.split.detail_scroll = row.min(u16::MAX as usize) as u16;
+            return;
+        }
+
+        self.state.split.detail_scroll = 0;
+        if let Some((table, len)) = self.state.active_table_mut() {
+            if len > 0 {
+                table.select(Some(row.min(len - 1)));
+            }
+        }
+    }
+
+    /// Select a row clicked in the table being shown, moving focus to the table
+    pub fn focus_row(&mut self, row: usize) {
+        self.state.split.focus = state::SplitFocus::List;
+        self.select_row(row);
+    }
+
+    /// Move the row selection of the table being shown up or down by a number
+    /// of rows, selecting the first row if none is selected
+    pub fn move_selection(&mut self, rows: isize) {
+        if self.state.is_detail_focused() {
+            self.state.split.scroll_detail(rows);
+            return;
+        }
+
+        self.state.split.detail_scroll = 0;
+        if let Some((table, len)) = self.state.active_table_mut() {
+            if len == 0 {
+                return;
+            }
+            let row = match table.selected() {
+                Some(selected) => selected.saturating_add_signed(rows).min(len - 1),
+                None => 0,
+            };
+            table.select(Some(row));
+        }
+    }
+
+    /// Select the last row of the table being shown
+    pub fn select_last_row(&mut self) {
+        self.select_row(usize::MAX);
+    }
+
+    /// Move the row selection of the table being shown a number of pages up
+    /// or down
+    pub fn move_pages(&mut self, pages: isize) {
+        self.move_selection(pages * PAGE_ROWS);
+    }
+
+    /// Ask for text to search the rows of the table being shown for
+    pub fn open_search(&mut self) {
+        if !self.state.navigation.current.has_list() {
+            return;
+        }
+
+        let dialog = domain::Dialog::prompt(
+            "Search",
+            "Search the rows for, then n and N for the next and previous match",
+            domain::Action::SearchFor,
+        );
+        self.dispatch(domain::Action::Dialog(dialog));
+    }
+
+    /// Search the rows of the table being shown, selecting the first match
+    /// from the row selected
+    pub fn search(&mut self, query: String) {
+        self.state.search = Some(query.to_lowercase());
+        self.search_step(0);
+    }
+
+    /// Select the next matching row, or the previous if `step` is negative,
+    /// wrapping round the table. A `step` of zero includes the selected row.
+    pub fn search_step(&mut self, step: isize) {
+        let Some(query) = self.state.search.clone() else {
+            let toast = domain::Toast::new("Press / to search the rows first")
+                .kind(domain::ToastKind::Info);
+            self.toast(toast);
+            return;
+        };
+
+        let rows = self.state.active_rows_text();
+        let Some((table, len)) = self.state.active_table_mut() else {
+            return;
+        };
+        if len == 0 {
+            return;
+        }
+
+        // Step through every row once, from the row after (or before) the
+        // selected row
+        let selected = table.selected().unwrap_or(0) as isize;
+        let direction = if step < 0 { -1 } else { 1 };
+        let start = if step == 0 { selected - 1 } else { selected };
+        let len = len as isize;
+        let matched = (1..=len)
+            .map(|offset| (start + offset * direction).rem_euclid(len) as usize)
+            .find(|row| rows[*row].to_lowercase().contains(&query));
+
+        match matched {
+            Some(row) => {
+                table.select(Some(row));
+                self.state.split.detail_scroll = 0;
+            }
+            None => {
+                let toast = domain::Toast::new(format!("No rows match `{query}`"))
+                    .kind(domain::ToastKind::Info);
+                self.toast(toast);
+            }
+        }
+    }
+}
@@ -1,6 +1,57 @@
 //-- ./src/controllers/mod.rs
 
-//! Application controllers
+//! Application controllers.
+//!
+//! Each controller handles the actions of its screens or feature and hands
+//! back the actions it does not handle, so new screens add their actions to
+//! their own controller rather than to `App::update`.
 //! ---
 
-mod update_backend_status;
\ No newline at end of file
+use crate::domain;
+
+mod audit_log;
+mod authentication;
+mod editor;
+mod forms;
+mod lists;
+mod navigation;
+mod sessions;
+mod update_backend_status;
+mod watch_logins;
+
+impl crate::App {
+    /// Offer an action to each controller in turn, until one handles it.
+    /// Actions no controller handles are dropped.
+    pub async fn update_controllers(
+        &mut self,
+        action: domain::Action,
+        terminal: &mut crate::Terminal,
+    ) {
+        let Some(action) = self.update_backend(action).await else {
+            return;
+        };
+        let Some(action) = self.update_logins_watch(action).await else {
+            return;
+        };
+        let Some(action) = self.update_authentication(action) else {
+            return;
+        };
+        let Some(action) = self.update_navigation(action) else {
+            return;
+        };
+        let Some(action) = self.update_lists(action) else {
+            return;
+        };
+        let Some(action) = self.update_forms(action, terminal).await else {
+            return;
+        };
+        let Some(action) = self.update_audit_log(action) else {
+            return;
+        };
+        let Some(action) = self.update_sessions(action).await else {
+            return;
+        };
+
+        tracing::trace!("No controller handled {action:?}");
+    }
+}
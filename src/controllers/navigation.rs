@@ -0,0 +1,104 @@
+//-- ./src/controllers/navigation.rs
+
+//! Route between the screens shown in the body.
+//!
+//! Screens that need a login are refused until logged in, and screens for
+//! logging in are refused once logged in
+
+use crate::domain;
+
+impl crate::App {
+    /// Handle the navigation actions, handing back any other action
+    pub fn update_navigation(
+        &mut self,
+        action: domain::Action,
+    ) -> Option<domain::Action> {
+        match action {
+            domain::Action::Navigate(screen) => self.navigate(screen),
+            domain::Action::NavigateBack => self.navigate_back(),
+            domain::Action::NavigateForward => self.navigate_forward(),
+            domain::Action::NavigateTab(index) => {
+                let is_logged_in = self.state.backend.access_token.is_some();
+                if let Some(screen) = domain::Screen::tabs(is_logged_in).get(index) {
+                    // Send back through the queue so the role is checked
+                    self.dispatch(domain::Action::Navigate(*screen));
+                }
+            }
+            action => return Some(action),
+        }
+
+        None
+    }
+
+    /// Show a screen, adding the current screen to the navigation history
+    pub fn navigate(&mut self, screen: domain::Screen) {
+        if !self.is_screen_available(screen) {
+            return;
+        }
+
+        // Leave input mode, the new screen starts in normal mode
+        self.set_mode(domain::AppMode::Normal);
+        self.state.navigation.navigate(screen);
+        self.enter_screen(screen);
+    }
+
+    /// Go back to the previous screen in the navigation history, passing over
+    /// screens not available with the current login and role
+    pub fn navigate_back(&mut self) {
+        let is_available = self.screen_filter();
+        if self.state.navigation.go_back(is_available) {
+            self.set_mode(domain::AppMode::Normal);
+            self.enter_screen(self.state.navigation.current);
+        }
+    }
+
+    /// Go forward to the screen gone back from, passing over screens not
+    /// available with the current login and role
+    pub fn navigate_forward(&mut self) {
+        let is_available = self.screen_filter();
+        if self.state.navigation.go_forward(is_available) {
+            self.set_mode(domain::AppMode::Normal);
+            self.enter_screen(self.state.navigation.current);
+        }
+    }
+
+    /// Which screens are available with the current login and role
+    fn screen_filter(&self) -> impl Fn(domain::Screen) -> bool {
+        let is_logged_in = self.state.backend.access_token.is_some();
+        let role = self.state.backend.role.clone();
+
+        move |screen| {
+            screen.is_available(is_logged_in)
+                && role.is_permitted(&domain::Action::Navigate(screen))
+        }
+    }
+
+    /// Is the screen available with the current login, toasting why not
+    fn is_screen_available(&mut self, screen: domain::Screen) -> bool {
+        let is_logged_in = self.state.backend.access_token.is_some();
+
+        if screen.is_available(is_logged_in) {
+            return true;
+        }
+
+        let toast_message = if is_logged_in {
+            format!("Already logged in, {screen} is not available")
+        } else {
+            format!("Login to view {screen}")
+        };
+
+        let toast = domain::Toast::new(toast_message).kind(domain::ToastKind::Info);
+        self.toast(toast);
+        false
+    }
+
+    /// Refresh the data a screen shows when it is entered
+    fn enter_screen(&mut self, screen: domain::Screen) {
+        match screen {
+            domain::Screen::AuditLog => self.load_audit_day(0),
+            // Fetch the logins now rather than at the next logins watch
+            domain::Screen::Logins => self.dispatch(domain::Action::LoginsWatch),
+            _ => {}
+        }
+    }
+}
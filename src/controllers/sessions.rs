@@ -0,0 +1,78 @@
+//-- ./src/controllers/sessions.rs
+
+//! Revoke the sessions of a user account, forcing it to log in again
+
+use crate::{client, domain, services};
+
+impl crate::App {
+    /// Handle the session revoke action, handing back any other action
+    pub async fn update_sessions(
+        &mut self,
+        action: domain::Action,
+    ) -> Option<domain::Action> {
+        match action {
+            domain::Action::RevokeSessions(email) => {
+                self.revoke_sessions(email).await
+            }
+            action => return Some(action),
+        }
+
+        None
+    }
+
+    /// Revoke every session of the user with the email address, recording the
+    /// revocation in the audit log
+    pub async fn revoke_sessions(&mut self, email: String) {
+        // We need to be logged in to revoke sessions
+        let Some(access_token) = self.state.backend.access_token.clone() else {
+            return;
+        };
+
+        //-- 1. Make the revoke request
+        let rpc_server_address = self.config.backend.address();
+        let response = match client::RpcClient::new(rpc_server_address).await {
+            Ok(rpc_client) => {
+                services::SessionsService::new(rpc_client)
+                    .revoke_user(&access_token, &email)
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+
+        //-- 2. Record the revocation in the audit log
+        let status = match &response {
+            Ok(_) => tonic::Code::Ok,
+            Err(error) => error.rpc_code(),
+        };
+        let mut entry = domain::AuditEntry::new(
+            self.operator_email(),
+            domain::AuditAction::RevokeSessions,
+            email.clone(),
+        )
+        .status(status);
+        if let Ok(revoked) = &response {
+            entry = entry.after(serde_json::json!({ "revoked": revoked }));
+        }
+        self.audit(entry);
+
+        //-- 3. Tell the operator the outcome
+        let toast = match response {
+            Ok(revoked) => {
+                domain::Toast::new(format!("Revoked {revoked} sessions for {email}"))
+                    .kind(domain::ToastKind::Success)
+            }
+            Err(error) => {
+                tracing::error!("Error revoking sessions: {}", error);
+                self.set_backend_rpc_error(&error);
+                let message = format!(
+                    "Unable to revoke sessions for {email}: {}",
+                    error.rpc_message()
+                );
+                domain::Toast::new(message)
+                    .kind(domain::ToastKind::Error)
+                    .follow_up("Retry", domain::Action::RevokeSessions(email))
+            }
+        };
+        self.toast(toast);
+    }
+}
@@ -4,19 +4,36 @@
 //! 
 //! A connection error will set the the status to Offline
 //! 
-//! A Ok response will set the status to Online
+//! A Ok response will set the status to Online, or LoggedIn while we hold an
+//! access token the backend accepts. A slow or failed response sets the status
+//! to Degraded.
 
 use std::time;
 
 use crate::{client, domain, services};
 
 impl crate::App {
+    /// Handle the backend status check action, handing back any other action
+    pub async fn update_backend(
+        &mut self,
+        action: domain::Action,
+    ) -> Option<domain::Action> {
+        match action {
+            domain::Action::BackendStatusUpdate => {
+                self.update_backend_status().await
+            }
+            action => return Some(action),
+        }
+
+        None
+    }
+
     /// Try to connect to the backend status and ping for a pong.
     /// 
     /// A connection error will set the the status to Offline
     /// 
-    /// A Ok response will set the status to Online
-    //TODO: Add connected status logic
+    /// A Ok response will set the status to Online, or LoggedIn while we hold
+    /// an access token the backend accepts
     pub async fn update_backend_status(&mut self) {
         // Set the status checked on time instance to now
         self.state.backend.status_checked_on = Some(time::Instant::now());
@@ -32,12 +49,12 @@ impl crate::App {
 
                 // Match call returned an error result
                 Err(error) => {
-                    // Set state to Offline on error
-                    self.state.backend.status = domain::BackendStatus::Offline;
-
                     // Send error to tracing log
                     tracing::error!("Error connecting to backend server: {}", error);
 
+                    // Set state to Offline on error
+                    self.set_backend_error(domain::BackendStatus::Offline, error);
+
                     // Return None
                     None
                 }
@@ -48,16 +65,46 @@ impl crate::App {
             // Construct a utilities service
             let mut utilities_service = services::UtilitiesService::new(rpc_client);
 
-            // Check if backend is online
-            if utilities_service.is_online().await {
-                // Set backend status to Online
-                self.state.backend.status = domain::BackendStatus::Online;
+            // Check if backend is online, keeping the round trip time
+            match utilities_service.ping().await {
+                Ok(latency) => self.record_latency(latency),
 
-            // Else false
-            } else {
-                // Set state to Offline
-                self.state.backend.status = domain::BackendStatus::Offline;
+                // Unreachable is offline, any other error is a partial failure
+                Err(error) => {
+                    tracing::error!("Error pinging backend server: {}", error);
+                    let status = match error.rpc_code() {
+                        tonic::Code::Unavailable => domain::BackendStatus::Offline,
+                        _ => domain::BackendStatus::Degraded,
+                    };
+                    self.set_backend_error(status, error.rpc_message());
+                }
             }
         }
     }
-}
\ No newline at end of file
+
+    /// Keep the ping round trip time, setting the status from it and from the
+    /// access token we hold
+    fn record_latency(&mut self, latency: time::Duration) {
+        self.state.backend.latency.push(latency);
+        self.dispatch(domain::Action::BackendLatency(latency));
+
+        // Slow pings are degraded
+        let threshold = self.config.backend.degraded_latency();
+        if self.state.backend.latency.is_degraded(threshold) {
+            let error = format!(
+                "ping took {}ms, over {}ms",
+                latency.as_millis(),
+                threshold.as_millis()
+            );
+            self.set_backend_error(domain::BackendStatus::Degraded, error);
+            return;
+        }
+
+        let status = if self.state.backend.access_token.is_some() {
+            domain::BackendStatus::LoggedIn
+        } else {
+            domain::BackendStatus::Online
+        };
+        self.set_backend_status(status);
+    }
+}
@@ -0,0 +1,93 @@
+//-- ./src/controllers/watch_logins.rs
+
+//! Poll the backend logins service for logins from IP addresses never seen
+//! before for that user account.
+//!
+//! A new IP address raises a warning toast and a security event. The logins
+//! fetched are kept for the logins list.
+
+use std::time;
+
+use crate::{client, domain, services};
+
+/// The number of most recent logins to request each poll
+const LOGINS_PAGE_SIZE: i64 = 100;
+
+impl crate::App {
+    /// Handle the logins watch action, handing back any other action
+    pub async fn update_logins_watch(
+        &mut self,
+        action: domain::Action,
+    ) -> Option<domain::Action> {
+        match action {
+            domain::Action::LoginsWatch => self.watch_logins().await,
+            action => return Some(action),
+        }
+
+        None
+    }
+
+    /// Poll the backend logins service for logins from IP addresses never seen
+    /// before for that user account.
+    ///
+    /// Seen IP addresses are recorded in the data directory, so new IP alerts
+    /// survive restarts of the TUI application.
+    pub async fn watch_logins(&mut self) {
+        // Set the logins checked on time instance to now
+        self.state.security.logins_checked_on = Some(time::Instant::now());
+
+        // We need to be logged in to read the logins
+        let Some(access_token) = self.state.backend.access_token.clone() else {
+            return;
+        };
+
+        // Assign socket address for communicating with the backend
+        let rpc_server_address = self.config.backend.address();
+
+        // Build the rpc client, returning early if error returned
+        let rpc_client = match client::RpcClient::new(rpc_server_address).await {
+            Ok(rpc_client) => rpc_client,
+            Err(error) => {
+                tracing::error!("Error connecting to backend server: {}", error);
+                self.set_backend_rpc_error(&error);
+                return;
+            }
+        };
+
+        // Get the most recent logins
+        let mut logins_service = services::LoginsService::new(rpc_client);
+        let logins = match logins_service
+            .index(&access_token, LOGINS_PAGE_SIZE, 0)
+            .await
+        {
+            Ok(logins) => logins,
+            Err(error) => {
+                tracing::error!("Error getting logins from backend: {}", error);
+                self.set_backend_rpc_error(&error);
+                return;
+            }
+        };
+
+        // Record each login IP, raising a security event for new ones
+        let previous_known_ips = self.state.security.known_ips.clone();
+        let events = self.state.security.record_logins(&logins);
+        self.state.logins.replace(logins);
+        for event in events {
+            tracing::warn!("Security event: {}", event.message());
+
+            let toast = domain::Toast::new(event.message())
+                .kind(domain::ToastKind::Warning);
+            self.toast(toast);
+
+            self.state.security.push_event(event);
+        }
+
+        // Persist the known IP record if it has changed
+        if self.state.security.known_ips != previous_known_ips {
+            let data_directory = &self.config.app.data_directory;
+            if let Err(error) = self.state.security.known_ips.save(data_directory) {
+                tracing::error!("Error saving known login IPs: {}", error);
+            }
+        }
+    }
+}
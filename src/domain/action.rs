@@ -3,28 +3,352 @@
 //! The list of application action types
 //! ---
 
-use crate::domain;
+use core::str;
+
+use crate::{domain, prelude::*};
 
 /// Application action types
 /// ---
 #[derive(Debug, Clone, PartialEq, strum::Display)]
 pub enum Action {
     AppMode(domain::AppMode),
+    /// Show the audit log entries for the next day.
+    AuditLogNextDay,
+    /// Show the audit log entries for the previous day.
+    AuditLogPreviousDay,
     /// Ping backend server status.
     BackendStatusUpdate,
     BackendStatus(domain::BackendStatus),
+    /// How long the last backend ping took to be answered.
+    BackendLatency(std::time::Duration),
+    /// A request to the backend started, or finished when false.
+    Busy(bool),
     ClearScreen,
+    /// Copy the text to the terminal clipboard.
+    Copy(String),
+    /// Open a modal dialog above the dialogs already open.
+    Dialog(domain::Dialog),
     Error(String),
+    /// Pass a key event to the form field in focus.
+    FormInput(crossterm::event::KeyEvent),
+    /// Move focus to the next form field.
+    FormNextField,
+    /// Move focus to the previous form field.
+    FormPreviousField,
+    /// Submit the form being shown.
+    FormSubmit,
+    /// Edit the form field in focus in `$VISUAL` or `$EDITOR`.
+    FormEdit,
+    /// The terminal gained focus, or lost it when false.
+    Focus(bool),
     Help,
     Nil,
+    /// Show the notification history.
+    Notifications,
+    /// The count of unread error and warning notifications changed.
+    NotificationsUnread(usize),
+    /// Show the command palette.
+    OpenCommandPalette,
     Paste(String),
+    /// The keys pressed so far of a key sequence or count, empty once done.
+    PendingKeys(String),
     Quit,
     Render,
+    /// Run the action a number of times, from a count typed before its keys.
+    Repeat(usize, Box<Action>),
     Resize(u16, u16),
+    /// Take the terminal back after a suspend, redrawing the whole screen.
     Resume,
+    /// Revoke every session of the user with the email address.
+    RevokeSessions(String),
+    /// The logged in operator email and when their access token expires.
+    Session(Option<String>, Option<chrono::DateTime<chrono::Utc>>),
+    /// Ask for text to search the rows of the table being shown for.
+    Search,
+    /// Search the rows of the table being shown, selecting the next match.
+    SearchFor(String),
+    /// Select the next row matching the search.
+    SearchNext,
+    /// Select the previous row matching the search.
+    SearchPrevious,
+    /// Move focus between the list and the detail of its selected row.
+    SplitFocus,
+    /// Grow the list pane, shrinking the detail pane.
+    SplitGrow,
+    /// Shrink the list pane, growing the detail pane.
+    SplitShrink,
+    /// Swap between side by side and stacked list and detail panes.
+    SplitFlip,
+    /// Suspend to the shell, as with Ctrl-Z.
     Suspend,
     Tick,
+    /// The access token has expired, so the backend will refuse it.
+    TokenExpired,
     Init,
+    /// Select the next row of the table being shown.
+    ListNext,
+    /// Select the previous row of the table being shown.
+    ListPrevious,
+    /// Select the first row of the table being shown.
+    ListFirst,
+    /// Select the last row of the table being shown.
+    ListLast,
+    /// Move the selection of the table being shown down a page.
+    ListPageDown,
+    /// Move the selection of the table being shown up a page.
+    ListPageUp,
+    /// Select a row of the table being shown, counting from zero.
+    ListSelect(usize),
+    /// Poll the logins service for logins from new IP addresses.
+    LoginsWatch,
+    /// Forget the access token and operator, returning to the login screen.
+    Logout,
+    /// Show a screen, adding the current screen to the history.
+    Navigate(domain::Screen),
+    /// Go back to the previous screen in the history.
+    NavigateBack,
+    /// Go forward to the screen gone back from.
+    NavigateForward,
+    /// Show the screen of the numbered tab, counting from zero.
+    NavigateTab(usize),
+    /// Dismiss the newest toast message.
     ClearToast,
     Toast(domain::Toast),
-}
\ No newline at end of file
+    /// Trigger the follow up action of the newest toast offering one.
+    ToastFollowUp,
+}
+
+impl Action {
+    /// Is the action only available to operators with the Admin role
+    pub fn is_admin_only(&self) -> bool {
+        match self {
+            Action::Navigate(screen) => screen.is_admin_only(),
+            // Refuse to ask for a confirmation that would be refused
+            Action::Dialog(domain::Dialog {
+                kind: domain::DialogKind::Confirm { on_yes },
+                ..
+            }) => on_yes.is_admin_only(),
+            Action::AuditLogNextDay
+            | Action::AuditLogPreviousDay
+            | Action::LoginsWatch
+            | Action::RevokeSessions(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Ask the operator to confirm a destructive action before it is sent,
+    /// however it was triggered. Other actions are returned as they are.
+    pub fn confirmed(self) -> Action {
+        match self {
+            Action::RevokeSessions(email) => {
+                let message = format!(
+                    "Revoke every session of {email}? \
+                    They will have to log in again."
+                );
+                let dialog = domain::Dialog::confirm(
+                    "Revoke sessions",
+                    message,
+                    Action::RevokeSessions(email),
+                );
+                Action::Dialog(dialog)
+            }
+            action => action,
+        }
+    }
+
+    /// Does the action make a request to the backend, blocking the event loop
+    /// until it is answered
+    pub fn is_request(&self) -> bool {
+        matches!(
+            self,
+            Action::BackendStatusUpdate
+                | Action::FormSubmit
+                | Action::LoginsWatch
+                | Action::RevokeSessions(_)
+        )
+    }
+
+    /// Can the action be repeated by a count typed before its keys, i.e. `5j`
+    pub fn is_repeatable(&self) -> bool {
+        matches!(
+            self,
+            Action::ListNext
+                | Action::ListPrevious
+                | Action::ListPageDown
+                | Action::ListPageUp
+                | Action::SearchNext
+                | Action::SearchPrevious
+                | Action::SplitGrow
+                | Action::SplitShrink
+        )
+    }
+
+    /// The help overlay group the action is listed under
+    pub fn category(&self) -> &'static str {
+        match self {
+            Action::Navigate(_)
+            | Action::NavigateBack
+            | Action::NavigateForward
+            | Action::NavigateTab(_) => "Navigation",
+            Action::AppMode(_)
+            | Action::FormInput(_)
+            | Action::FormNextField
+            | Action::FormPreviousField
+            | Action::FormSubmit
+            | Action::FormEdit => "Forms",
+            Action::AuditLogNextDay | Action::AuditLogPreviousDay => "Audit log",
+            Action::ListNext
+            | Action::ListPrevious
+            | Action::ListFirst
+            | Action::ListLast
+            | Action::ListPageDown
+            | Action::ListPageUp
+            | Action::ListSelect(_)
+            | Action::Search
+            | Action::SearchFor(_)
+            | Action::SearchNext
+            | Action::SearchPrevious
+            | Action::SplitFocus
+            | Action::SplitGrow
+            | Action::SplitShrink
+            | Action::SplitFlip => "Lists",
+            Action::BackendStatusUpdate
+            | Action::LoginsWatch
+            | Action::RevokeSessions(_) => "Backend",
+            _ => "Application",
+        }
+    }
+
+    /// Describe what the action does, for the help overlay
+    pub fn description(&self) -> String {
+        match self {
+            Action::AppMode(mode) => format!("Switch to {mode} mode"),
+            Action::AuditLogNextDay => "Show the next day".to_string(),
+            Action::AuditLogPreviousDay => "Show the previous day".to_string(),
+            Action::BackendStatusUpdate => "Check the backend status".to_string(),
+            Action::ClearScreen => "Clear the screen".to_string(),
+            Action::ClearToast => "Dismiss the newest toast message".to_string(),
+            Action::ToastFollowUp => "Run the toast follow up, i.e. Retry".to_string(),
+            Action::FormNextField => "Next form field".to_string(),
+            Action::FormPreviousField => "Previous form field".to_string(),
+            Action::FormSubmit => "Submit the form".to_string(),
+            Action::FormEdit => "Edit the field in $EDITOR".to_string(),
+            Action::Help => "Show this help".to_string(),
+            Action::ListNext => "Select the next row".to_string(),
+            Action::ListPrevious => "Select the previous row".to_string(),
+            Action::ListFirst => "Select the first row".to_string(),
+            Action::ListLast => {
+                "Select the last row, or row N with a count".to_string()
+            }
+            Action::ListPageDown => "Move the selection down a page".to_string(),
+            Action::ListPageUp => "Move the selection up a page".to_string(),
+            Action::ListSelect(row) => format!("Select row {}", row + 1),
+            Action::LoginsWatch => "Check logins for new IP addresses".to_string(),
+            Action::Logout => "Log out".to_string(),
+            Action::Navigate(screen) => format!("Go to {screen}"),
+            Action::NavigateBack => "Go back".to_string(),
+            Action::NavigateForward => "Go forward".to_string(),
+            Action::NavigateTab(index) => format!("Go to tab {}", index + 1),
+            Action::Nil => "Do nothing".to_string(),
+            Action::Notifications => "Show the notification history".to_string(),
+            Action::OpenCommandPalette => "Open the command palette".to_string(),
+            Action::Quit => "Quit".to_string(),
+            Action::Search => "Search the rows".to_string(),
+            Action::SearchNext => "Select the next search match".to_string(),
+            Action::SearchPrevious => "Select the previous search match".to_string(),
+            Action::SplitFocus => "Switch focus to the list or detail".to_string(),
+            Action::SplitGrow => "Grow the list pane".to_string(),
+            Action::SplitShrink => "Shrink the list pane".to_string(),
+            Action::SplitFlip => "Split side by side or stacked".to_string(),
+            Action::Suspend => "Suspend to the shell".to_string(),
+            action => action.to_string(),
+        }
+    }
+
+    /// Is the action of any use on the screen, so the help overlay only
+    /// lists the keys that do something where the operator is
+    pub fn is_relevant_to(&self, screen: &domain::Screen) -> bool {
+        match self {
+            Action::AuditLogNextDay | Action::AuditLogPreviousDay => {
+                *screen == domain::Screen::AuditLog
+            }
+            Action::AppMode(domain::AppMode::Input)
+            | Action::FormNextField
+            | Action::FormPreviousField
+            | Action::FormSubmit
+            | Action::FormEdit => screen.requires_logout(),
+            Action::Logout => !screen.requires_logout(),
+            Action::SplitFocus
+            | Action::SplitGrow
+            | Action::SplitShrink
+            | Action::SplitFlip => screen.has_detail(),
+            action if action.category() == "Lists" => screen.has_list(),
+            _ => true,
+        }
+    }
+}
+
+/// Parse an action from its name, as used in the key bindings config, e.g.
+/// `Quit`, `Navigate(Home)` or `NavigateTab(0)`. Only actions that make sense
+/// bound to a key can be parsed.
+impl str::FromStr for Action {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let unknown = || Error::Keybinding(format!("unknown action `{s}`"));
+
+        // Split an argument out of the name, i.e. `Navigate(Home)`
+        let (name, argument) = match s.trim().split_once('(') {
+            Some((name, rest)) => {
+                let argument = rest.strip_suffix(')').ok_or_else(unknown)?;
+                (name, Some(argument.trim()))
+            }
+            None => (s.trim(), None),
+        };
+
+        let action = match (name, argument) {
+            ("AppMode", Some("Normal")) => Action::AppMode(domain::AppMode::Normal),
+            ("AppMode", Some("Input")) => Action::AppMode(domain::AppMode::Input),
+            ("AuditLogNextDay", None) => Action::AuditLogNextDay,
+            ("AuditLogPreviousDay", None) => Action::AuditLogPreviousDay,
+            ("BackendStatusUpdate", None) => Action::BackendStatusUpdate,
+            ("ClearScreen", None) => Action::ClearScreen,
+            ("ClearToast", None) => Action::ClearToast,
+            ("ToastFollowUp", None) => Action::ToastFollowUp,
+            ("FormNextField", None) => Action::FormNextField,
+            ("FormPreviousField", None) => Action::FormPreviousField,
+            ("FormSubmit", None) => Action::FormSubmit,
+            ("FormEdit", None) => Action::FormEdit,
+            ("Help", None) => Action::Help,
+            ("ListNext", None) => Action::ListNext,
+            ("ListPrevious", None) => Action::ListPrevious,
+            ("ListFirst", None) => Action::ListFirst,
+            ("ListLast", None) => Action::ListLast,
+            ("ListPageDown", None) => Action::ListPageDown,
+            ("ListPageUp", None) => Action::ListPageUp,
+            ("LoginsWatch", None) => Action::LoginsWatch,
+            ("Logout", None) => Action::Logout,
+            ("Navigate", Some(screen)) => Action::Navigate(screen.parse()?),
+            ("NavigateBack", None) => Action::NavigateBack,
+            ("NavigateForward", None) => Action::NavigateForward,
+            ("NavigateTab", Some(index)) => {
+                Action::NavigateTab(index.parse().map_err(|_| unknown())?)
+            }
+            ("Nil", None) => Action::Nil,
+            ("Notifications", None) => Action::Notifications,
+            ("OpenCommandPalette", None) => Action::OpenCommandPalette,
+            ("Quit", None) => Action::Quit,
+            ("Search", None) => Action::Search,
+            ("SearchNext", None) => Action::SearchNext,
+            ("SearchPrevious", None) => Action::SearchPrevious,
+            ("SplitFocus", None) => Action::SplitFocus,
+            ("SplitGrow", None) => Action::SplitGrow,
+            ("SplitShrink", None) => Action::SplitShrink,
+            ("SplitFlip", None) => Action::SplitFlip,
+            ("Suspend", None) => Action::Suspend,
+            _ => return Err(unknown()),
+        };
+
+        Ok(action)
+    }
+}
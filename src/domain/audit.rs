@@ -0,0 +1,90 @@
+//-- ./src/domain/audit.rs
+
+// #![allow(unused)] // For development only
+
+//! Define the audit log entry domain, a record of an administrative action
+//! made through the TUI, or of a self-service account change made from its
+//! forms before logging in
+//! ---
+
+/// Enum list of audited administrative and self-service actions
+#[derive(
+    Debug, Clone, PartialEq, strum::Display, serde::Serialize, serde::Deserialize,
+)]
+pub enum AuditAction {
+    /// An operator revoked every session of a user account
+    RevokeSessions,
+    /// Someone not logged in registered an account for themselves
+    SelfRegister,
+    /// Someone not logged in reset a password with an emailed reset token
+    SelfResetPassword,
+}
+
+/// Audit log entry type structure
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct AuditEntry {
+    /// When the action was made
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+
+    /// Email address of the operator that made the action, none for a
+    /// self-service action made by someone not logged in
+    #[serde(default)]
+    pub operator: Option<String>,
+
+    /// The administrative action made
+    pub action: AuditAction,
+
+    /// What the action was made against, a user email, or for a password
+    /// reset the user id the reset token was issued for
+    pub target: String,
+
+    /// The value after the action, i.e. the number of sessions revoked
+    pub after: Option<serde_json::Value>,
+
+    /// The RPC status code returned by the backend
+    pub status: String,
+}
+
+impl AuditEntry {
+    /// Create a new audit entry, time stamped now
+    pub fn new(
+        operator: impl Into<String>,
+        action: AuditAction,
+        target: impl Into<String>,
+    ) -> Self {
+        Self {
+            timestamp: chrono::Utc::now(),
+            operator: Some(operator.into()),
+            action,
+            target: target.into(),
+            after: None,
+            status: format!("{:?}", tonic::Code::Unknown),
+        }
+    }
+
+    /// Create a new self-service audit entry, time stamped now, made by
+    /// someone not logged in so with no operator
+    pub fn self_service(action: AuditAction, target: impl Into<String>) -> Self {
+        Self {
+            operator: None,
+            ..Self::new(String::new(), action, target)
+        }
+    }
+
+    /// The operator to show, or that the action was self-service
+    pub fn operator_label(&self) -> &str {
+        self.operator.as_deref().unwrap_or("self-service")
+    }
+
+    /// Set the value after the action
+    pub fn after(mut self, after: impl serde::Serialize) -> Self {
+        self.after = serde_json::to_value(after).ok();
+        self
+    }
+
+    /// Set the RPC status code returned by the backend
+    pub fn status(mut self, code: tonic::Code) -> Self {
+        self.status = format!("{code:?}");
+        self
+    }
+}
@@ -10,6 +10,9 @@ use core::fmt;
 /// Authentication backend server status
 #[derive(Debug, Clone, PartialEq)]
 pub enum BackendStatus {
+    /// The first status check has not been answered yet
+    Connecting,
+
     /// Backend server can not be reached, so must be off line
     Offline,
 
@@ -18,19 +21,62 @@ pub enum BackendStatus {
 
     /// Backend server can be reached and we are logged in
     LoggedIn,
+
+    /// Backend server answers slowly or with errors
+    Degraded,
+
+    /// Backend server can be reached, but refuses our access token, i.e. it
+    /// has expired
+    Unauthorized,
+
+    /// Backend server version is not one the TUI supports
+    Incompatible,
 }
 
 /// Imply default backend status
 impl Default for BackendStatus {
-    fn default() -> Self { BackendStatus::Offline }
+    fn default() -> Self { BackendStatus::Connecting }
+}
+
+impl BackendStatus {
+    /// Is the backend unreachable or struggling, so worth checking more often
+    pub fn is_unhealthy(&self) -> bool {
+        matches!(
+            self,
+            BackendStatus::Connecting
+                | BackendStatus::Offline
+                | BackendStatus::Degraded
+        )
+    }
+
+    /// Explain the status to the operator
+    pub fn description(&self) -> &'static str {
+        match self {
+            BackendStatus::Connecting => "Waiting for the first status check",
+            BackendStatus::Offline => "The backend server can not be reached",
+            BackendStatus::Online => "The backend server is up, log in to manage it",
+            BackendStatus::LoggedIn => "We are logged in to the backend server",
+            BackendStatus::Degraded => "The backend server is slow or failing",
+            BackendStatus::Unauthorized => {
+                "The backend server refuses our access token, log in again"
+            }
+            BackendStatus::Incompatible => {
+                "The backend server version is not supported by this TUI"
+            }
+        }
+    }
 }
 
 impl fmt::Display for BackendStatus {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
        match self {
+           BackendStatus::Connecting => write!(f, "Connecting"),
            BackendStatus::Offline => write!(f, "Offline"),
            BackendStatus::Online => write!(f, "Online"),
            BackendStatus::LoggedIn => write!(f, "Logged In"),
+           BackendStatus::Degraded => write!(f, "Degraded"),
+           BackendStatus::Unauthorized => write!(f, "Unauthorized"),
+           BackendStatus::Incompatible => write!(f, "Incompatible"),
        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,145 @@
+//-- ./src/domain/backend_version.rs
+
+// #![allow(unused)] // For development only
+
+//! The backend server version, and whether the TUI supports it
+//! ---
+
+use core::{fmt, ops, str};
+
+use crate::prelude::*;
+
+/// How well the TUI supports a backend version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionSupport {
+    /// Within the supported range
+    Supported,
+
+    /// Outside the supported range, but a supported major version, so the
+    /// protocol should still match
+    Untested,
+
+    /// A major version the TUI does not support, so the protocol has drifted
+    Incompatible,
+}
+
+/// Backend server semantic version, ordered major first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BackendVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl BackendVersion {
+    /// Construct a version from its parts
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// How well a TUI supporting the range of versions, from the first
+    /// supported up to but not including the first unsupported, supports this
+    /// version
+    pub fn support(&self, supported: &ops::Range<BackendVersion>) -> VersionSupport {
+        // The majors the range reaches into, i.e. up to 3 for `1.2.0..2.1.0`
+        // but 2 for `1.2.0..2.0.0`, and at least the first one
+        let reaches_end_major =
+            supported.end.minor > 0 || supported.end.patch > 0;
+        let last_major = (supported.end.major + u64::from(reaches_end_major))
+            .max(supported.start.major + 1);
+
+        if supported.contains(self) {
+            VersionSupport::Supported
+        } else if (supported.start.major..last_major).contains(&self.major) {
+            VersionSupport::Untested
+        } else {
+            VersionSupport::Incompatible
+        }
+    }
+}
+
+impl fmt::Display for BackendVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Parse a version as reported by the backend, i.e. `v1.2.3-beta`, ignoring
+/// any pre-release or build suffix and taking missing parts as zero
+impl str::FromStr for BackendVersion {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim().trim_start_matches('v');
+        let core = trimmed.split(['-', '+']).next().unwrap_or_default();
+
+        let mut parts = [0; 3];
+        let numbers: Vec<&str> = core.split('.').collect();
+        if numbers.len() > parts.len() {
+            return Err(Error::Version(format!("too many parts in `{s}`")));
+        }
+        for (part, number) in parts.iter_mut().zip(numbers) {
+            *part = number
+                .parse()
+                .map_err(|_| Error::Version(format!("`{s}` is not a version")))?;
+        }
+
+        Ok(Self::new(parts[0], parts[1], parts[2]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn versions_parse_and_compare_with_the_supported_range() {
+        //-- Setup and Fixtures (Arrange)
+        let supported = BackendVersion::new(1, 2, 0)..BackendVersion::new(2, 0, 0);
+        let version = |s: &str| s.parse::<BackendVersion>().unwrap();
+
+        //-- Execute Function (Act)
+        let parsed = version("v1.4.2-beta+42");
+
+        //-- Checks (Assertions)
+        assert_eq!(parsed, BackendVersion::new(1, 4, 2));
+        assert_eq!(version("2"), BackendVersion::new(2, 0, 0));
+        assert!("1.x".parse::<BackendVersion>().is_err());
+        assert!("1.2.3.4".parse::<BackendVersion>().is_err());
+        assert_eq!(parsed.support(&supported), VersionSupport::Supported);
+        assert_eq!(
+            version("1.1.9").support(&supported),
+            VersionSupport::Untested
+        );
+        assert_eq!(
+            version("2.0.0").support(&supported),
+            VersionSupport::Incompatible
+        );
+        assert_eq!(
+            version("0.9.0").support(&supported),
+            VersionSupport::Incompatible
+        );
+    }
+
+    #[test]
+    fn a_range_ending_within_a_major_supports_that_major() {
+        //-- Setup and Fixtures (Arrange)
+        let supported = BackendVersion::new(1, 2, 0)..BackendVersion::new(2, 1, 0);
+
+        //-- Execute Function (Act)
+        let support = |major, minor| {
+            BackendVersion::new(major, minor, 0).support(&supported)
+        };
+
+        //-- Checks (Assertions)
+        assert_eq!(support(2, 0), VersionSupport::Supported);
+        assert_eq!(support(2, 3), VersionSupport::Untested);
+        assert_eq!(support(1, 0), VersionSupport::Untested);
+        assert_eq!(support(3, 0), VersionSupport::Incompatible);
+    }
+}
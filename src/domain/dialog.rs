@@ -0,0 +1,133 @@
+//-- ./src/domain/dialog.rs
+
+// #![allow(unused)] // For development only
+
+//! Define the modal dialog domain
+//! ---
+
+use std::ptr;
+
+use crate::domain;
+
+/// What a dialog asks for, and how its answer becomes an action
+#[derive(Debug, Clone)]
+pub enum DialogKind {
+    /// Ask a yes or no question, sending the action on yes
+    Confirm { on_yes: Box<domain::Action> },
+
+    /// Ask for a line of text, sending the action built from it
+    Prompt {
+        on_submit: fn(String) -> domain::Action,
+    },
+
+    /// Pick one of the options, sending the action built from it
+    Select {
+        options: Vec<String>,
+        on_select: fn(String) -> domain::Action,
+    },
+
+    /// Show the full detail of an error, with nothing to answer
+    ErrorDetail,
+}
+
+/// Compare dialog kinds, the answer functions by address
+impl PartialEq for DialogKind {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Confirm { on_yes }, Self::Confirm { on_yes: other }) => {
+                on_yes == other
+            }
+            (Self::Prompt { on_submit }, Self::Prompt { on_submit: other }) => {
+                ptr::fn_addr_eq(*on_submit, *other)
+            }
+            (
+                Self::Select { options, on_select },
+                Self::Select {
+                    options: other_options,
+                    on_select: other,
+                },
+            ) => options == other_options && ptr::fn_addr_eq(*on_select, *other),
+            (Self::ErrorDetail, Self::ErrorDetail) => true,
+            _ => false,
+        }
+    }
+}
+
+/// A modal dialog, shown above the screens until it is answered or cancelled
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dialog {
+    /// Dialog title
+    pub(crate) title: String,
+
+    /// The question, or the error detail
+    pub(crate) message: String,
+
+    /// What the dialog asks for
+    pub(crate) kind: DialogKind,
+}
+
+impl Dialog {
+    /// Ask a yes or no question, sending the action on yes
+    pub fn confirm(
+        title: impl Into<String>,
+        message: impl Into<String>,
+        on_yes: domain::Action,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            kind: DialogKind::Confirm {
+                on_yes: Box::new(on_yes),
+            },
+        }
+    }
+
+    /// Ask for a line of text, sending the action built from it
+    pub fn prompt(
+        title: impl Into<String>,
+        message: impl Into<String>,
+        on_submit: fn(String) -> domain::Action,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            kind: DialogKind::Prompt { on_submit },
+        }
+    }
+
+    /// Pick one of the options, sending the action built from it
+    pub fn select(
+        title: impl Into<String>,
+        message: impl Into<String>,
+        options: Vec<String>,
+        on_select: fn(String) -> domain::Action,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            kind: DialogKind::Select { options, on_select },
+        }
+    }
+
+    /// Show the full detail of an error
+    pub fn error_detail(
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            kind: DialogKind::ErrorDetail,
+        }
+    }
+
+    /// The buttons along the bottom of the dialog, in focus order
+    pub fn buttons(&self) -> &'static [&'static str] {
+        match self.kind {
+            DialogKind::Confirm { .. } => &["Yes", "No"],
+            DialogKind::Prompt { .. } => &["OK", "Cancel"],
+            DialogKind::Select { .. } => &["Select", "Cancel"],
+            DialogKind::ErrorDetail => &["OK"],
+        }
+    }
+}
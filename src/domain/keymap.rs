@@ -0,0 +1,333 @@
+//-- ./src/domain/keymap.rs
+
+// #![allow(unused)] // For development only
+
+//! Map key chords and key sequences to actions, for each application mode
+//! ---
+
+use core::{fmt, str};
+use std::time;
+
+use crossterm::event as crossterm;
+
+use crate::{config, domain, prelude::*};
+
+/// A key with its modifiers, i.e. `ctrl-p`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyChord {
+    pub code: crossterm::KeyCode,
+    pub modifiers: crossterm::KeyModifiers,
+}
+
+impl KeyChord {
+    /// Modifiers a chord can be bound with
+    fn bindable_modifiers() -> crossterm::KeyModifiers {
+        crossterm::KeyModifiers::CONTROL
+            | crossterm::KeyModifiers::ALT
+            | crossterm::KeyModifiers::SHIFT
+    }
+
+    /// Construct a chord, dropping the shift modifier where it is already
+    /// part of the key, so `H` and `backtab` match however the terminal
+    /// reports them
+    pub fn new(
+        code: crossterm::KeyCode,
+        modifiers: crossterm::KeyModifiers,
+    ) -> Self {
+        let mut modifiers = modifiers & Self::bindable_modifiers();
+
+        if matches!(
+            code,
+            crossterm::KeyCode::Char(_) | crossterm::KeyCode::BackTab
+        ) {
+            modifiers.remove(crossterm::KeyModifiers::SHIFT);
+        }
+
+        Self { code, modifiers }
+    }
+}
+
+impl From<crossterm::KeyEvent> for KeyChord {
+    fn from(key_event: crossterm::KeyEvent) -> Self {
+        Self::new(key_event.code, key_event.modifiers)
+    }
+}
+
+/// Parse a chord from the key bindings config, i.e. `ctrl-p`, `alt-left`, `G`
+impl str::FromStr for KeyChord {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let unknown = || Error::Keybinding(format!("unknown key `{s}`"));
+
+        //-- 1. Strip the modifier prefixes
+        let mut modifiers = crossterm::KeyModifiers::NONE;
+        let mut key = s;
+        loop {
+            let lower = key.to_lowercase();
+            let (modifier, prefix_len) =
+                if lower.starts_with("ctrl-") && key.len() > 5 {
+                    (crossterm::KeyModifiers::CONTROL, 5)
+                } else if lower.starts_with("alt-") && key.len() > 4 {
+                    (crossterm::KeyModifiers::ALT, 4)
+                } else if lower.starts_with("shift-") && key.len() > 6 {
+                    (crossterm::KeyModifiers::SHIFT, 6)
+                } else {
+                    break;
+                };
+            modifiers.insert(modifier);
+            key = &key[prefix_len..];
+        }
+
+        //-- 2. Parse the key name, a single character is the character key
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => crossterm::KeyCode::Char(c),
+            _ => match key.to_lowercase().as_str() {
+                "esc" => crossterm::KeyCode::Esc,
+                "enter" => crossterm::KeyCode::Enter,
+                "tab" => crossterm::KeyCode::Tab,
+                "backtab" => crossterm::KeyCode::BackTab,
+                "backspace" => crossterm::KeyCode::Backspace,
+                "delete" => crossterm::KeyCode::Delete,
+                "insert" => crossterm::KeyCode::Insert,
+                "home" => crossterm::KeyCode::Home,
+                "end" => crossterm::KeyCode::End,
+                "pageup" => crossterm::KeyCode::PageUp,
+                "pagedown" => crossterm::KeyCode::PageDown,
+                "up" => crossterm::KeyCode::Up,
+                "down" => crossterm::KeyCode::Down,
+                "left" => crossterm::KeyCode::Left,
+                "right" => crossterm::KeyCode::Right,
+                "space" => crossterm::KeyCode::Char(' '),
+                name => {
+                    let number = name.strip_prefix('f').ok_or_else(unknown)?;
+                    crossterm::KeyCode::F(number.parse().map_err(|_| unknown())?)
+                }
+            },
+        };
+
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.modifiers.contains(crossterm::KeyModifiers::CONTROL) {
+            write!(f, "ctrl-")?;
+        }
+        if self.modifiers.contains(crossterm::KeyModifiers::ALT) {
+            write!(f, "alt-")?;
+        }
+        if self.modifiers.contains(crossterm::KeyModifiers::SHIFT) {
+            write!(f, "shift-")?;
+        }
+
+        match self.code {
+            crossterm::KeyCode::Char(' ') => write!(f, "space"),
+            crossterm::KeyCode::Char(c) => write!(f, "{c}"),
+            crossterm::KeyCode::F(number) => write!(f, "f{number}"),
+            code => write!(f, "{}", format!("{code:?}").to_lowercase()),
+        }
+    }
+}
+
+/// A key sequence bound to an action
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyBinding {
+    /// The chords pressed in order, i.e. `g g`
+    pub keys: Vec<KeyChord>,
+
+    /// The action sent once every chord is pressed
+    pub action: domain::Action,
+}
+
+impl KeyBinding {
+    /// Parse a key sequence and action name from the key bindings config
+    fn parse(keys: &str, action: &str) -> Result<Self> {
+        let keys = keys
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<KeyChord>>>()?;
+
+        if keys.is_empty() {
+            return Err(Error::Keybinding("empty key sequence".to_string()));
+        }
+
+        let action = action.parse()?;
+
+        Ok(Self { keys, action })
+    }
+
+    /// The key sequence as it is written in the config file
+    pub fn keys_label(&self) -> String {
+        let keys: Vec<String> = self.keys.iter().map(KeyChord::to_string).collect();
+        keys.join(" ")
+    }
+}
+
+/// The result of looking up the keys pressed so far
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyLookup {
+    /// The keys are bound to the action
+    Action(domain::Action),
+
+    /// The keys start a longer key sequence, wait for the next key
+    Pending,
+
+    /// The keys are not bound
+    Unbound,
+}
+
+/// The key bindings for each application mode
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Keymap {
+    normal: Vec<KeyBinding>,
+    input: Vec<KeyBinding>,
+
+    /// How long to wait for the next key of a sequence or count
+    timeout: time::Duration,
+}
+
+impl Keymap {
+    /// Build the keymap from the key bindings config, failing on unknown keys,
+    /// unknown action names and conflicting bindings
+    pub fn new(config: &config::KeybindingsConfig) -> Result<Self> {
+        let normal = parse_mode("normal", &config.normal)?;
+        let input = parse_mode("input", &config.input)?;
+        let timeout = time::Duration::from_millis(config.sequence_timeout_ms);
+
+        Ok(Self {
+            normal,
+            input,
+            timeout,
+        })
+    }
+
+    /// How long to wait for the next key of a sequence or count
+    pub fn timeout(&self) -> time::Duration {
+        self.timeout
+    }
+
+    /// The key bindings of the application mode
+    pub fn bindings(&self, app_mode: &domain::AppMode) -> &[KeyBinding] {
+        match app_mode {
+            domain::AppMode::Normal => &self.normal,
+            domain::AppMode::Input => &self.input,
+        }
+    }
+
+    /// Look up the keys pressed so far in the application mode
+    pub fn lookup(
+        &self,
+        app_mode: &domain::AppMode,
+        keys: &[KeyChord],
+    ) -> KeyLookup {
+        let bindings = self.bindings(app_mode);
+
+        if let Some(binding) = bindings.iter().find(|binding| binding.keys == keys) {
+            return KeyLookup::Action(binding.action.clone());
+        }
+
+        if bindings
+            .iter()
+            .any(|binding| binding.keys.starts_with(keys))
+        {
+            return KeyLookup::Pending;
+        }
+
+        KeyLookup::Unbound
+    }
+}
+
+/// Parse the bindings of a mode, checking no key sequence is the same as, or
+/// starts, another key sequence
+fn parse_mode(
+    mode: &str,
+    config: &std::collections::BTreeMap<String, String>,
+) -> Result<Vec<KeyBinding>> {
+    let mut bindings: Vec<KeyBinding> = Vec::new();
+
+    for (keys, action) in config {
+        let binding = KeyBinding::parse(keys, action).map_err(|error| {
+            Error::Keybinding(format!("[keybindings.{mode}] \"{keys}\": {error}"))
+        })?;
+
+        let conflict = bindings.iter().find(|other| {
+            other.keys.starts_with(&binding.keys)
+                || binding.keys.starts_with(&other.keys)
+        });
+        if let Some(other) = conflict {
+            return Err(Error::Keybinding(format!(
+                "[keybindings.{mode}] \"{}\" conflicts with \"{}\"",
+                keys,
+                other.keys_label()
+            )));
+        }
+
+        bindings.push(binding);
+    }
+
+    Ok(bindings)
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn default_keybindings_are_valid() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let config = config::KeybindingsConfig::default();
+        let ctrl_p = KeyChord::new(
+            crossterm::KeyCode::Char('p'),
+            crossterm::KeyModifiers::CONTROL,
+        );
+
+        //-- Execute Function (Act)
+        let keymap = Keymap::new(&config)?;
+
+        //-- Checks (Assertions)
+        assert_eq!(
+            keymap.lookup(&domain::AppMode::Input, &[ctrl_p]),
+            KeyLookup::Action(domain::Action::OpenCommandPalette)
+        );
+
+        //-- Return
+        Ok(())
+    }
+
+    #[test]
+    fn key_sequence_prefix_conflicts() {
+        //-- Setup and Fixtures (Arrange)
+        let mut config = config::KeybindingsConfig::default();
+        config
+            .normal
+            .insert("g g".to_string(), "Navigate(Home)".to_string());
+        config.normal.insert("g".to_string(), "Quit".to_string());
+
+        //-- Execute Function (Act)
+        let keymap = Keymap::new(&config);
+
+        //-- Checks (Assertions)
+        assert!(keymap.is_err());
+    }
+
+    #[test]
+    fn unknown_action_name_is_an_error() {
+        //-- Setup and Fixtures (Arrange)
+        let mut config = config::KeybindingsConfig::default();
+        config.normal.insert("x".to_string(), "Explode".to_string());
+
+        //-- Execute Function (Act)
+        let keymap = Keymap::new(&config);
+
+        //-- Checks (Assertions)
+        assert!(keymap.is_err());
+    }
+}
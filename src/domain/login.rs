@@ -0,0 +1,87 @@
+//-- ./src/domain/login.rs
+
+// #![allow(unused)] // For development only
+
+//! Define the login record domain, a successful login recorded by the backend
+//! ---
+
+use std::net;
+
+use crate::client;
+
+/// Login record type structure
+#[derive(Debug, Clone, PartialEq)]
+pub struct Login {
+    /// Login record unique identifier
+    pub id: String,
+
+    /// The user the login belongs to
+    pub user_id: String,
+
+    /// When the login happened, as formatted by the backend
+    pub login_on: String,
+
+    /// The IP address the login came from, if recorded
+    pub login_ip: Option<net::IpAddr>,
+}
+
+impl From<client::rpc::LoginResponse> for Login {
+    fn from(login: client::rpc::LoginResponse) -> Self {
+        // The backend stores IPv4 addresses as a 32 bit integer, an integer
+        // that is not one is logged and dropped rather than cut down to fit
+        let login_ip = login.login_ip.and_then(|ip| {
+            let ipv4 = ipv4_from_integer(i64::from(ip));
+            if ipv4.is_none() {
+                tracing::warn!(
+                    "Ignoring login {} IP {}, it is not an IPv4 address",
+                    login.id,
+                    ip
+                );
+            }
+            ipv4.map(net::IpAddr::V4)
+        });
+
+        Self {
+            id: login.id,
+            user_id: login.user_id,
+            login_on: login.login_on,
+            login_ip,
+        }
+    }
+}
+
+/// An IPv4 address from the integer the backend stores it as, either unsigned
+/// or as the bit pattern of a signed 32 bit integer. The integer field can not
+/// carry an IPv6 address, so anything wider is not an address.
+fn ipv4_from_integer(ip: i64) -> Option<net::Ipv4Addr> {
+    let bits = match u32::try_from(ip) {
+        Ok(bits) => bits,
+        Err(_) => u32::from_be_bytes(i32::try_from(ip).ok()?.to_be_bytes()),
+    };
+
+    Some(net::Ipv4Addr::from(bits))
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn integers_that_do_not_fit_are_not_addresses() {
+        //-- Setup and Fixtures (Arrange)
+        let localhost = i64::from(u32::from(net::Ipv4Addr::LOCALHOST));
+
+        //-- Execute Function (Act)
+        let ipv4 = ipv4_from_integer(localhost);
+
+        //-- Checks (Assertions)
+        assert_eq!(ipv4, Some(net::Ipv4Addr::LOCALHOST));
+        assert_eq!(
+            ipv4_from_integer(-1),
+            Some(net::Ipv4Addr::new(255, 255, 255, 255))
+        );
+        assert_eq!(ipv4_from_integer(i64::from(u32::MAX) + 1), None);
+        assert_eq!(ipv4_from_integer(i64::from(i32::MIN) - 1), None);
+    }
+}
@@ -8,9 +8,41 @@ pub use action::Action;
 mod app_modes;
 pub use app_modes::AppMode;
 
+/// Audit log entry type module
+mod audit;
+pub use audit::{AuditAction, AuditEntry};
+
 mod backend_status;
 pub use backend_status::BackendStatus;
 
+/// Backend version type module
+mod backend_version;
+pub use backend_version::{BackendVersion, VersionSupport};
+
+/// Modal dialog type module
+mod dialog;
+pub use dialog::{Dialog, DialogKind};
+
+/// Key chord to action mapping module
+mod keymap;
+pub use keymap::{KeyBinding, KeyChord, KeyLookup, Keymap};
+
+/// Login record type module
+mod login;
+pub use login::Login;
+
+/// Screen (route) type module
+mod screen;
+pub use screen::Screen;
+
+/// Security event type module
+mod security_event;
+pub use security_event::{SecurityEvent, SecurityEventKind};
+
+/// JSON Web Token claims type module
+mod token_claims;
+pub use token_claims::TokenClaims;
+
 /// Toast message type module
 mod toast;
 mod event;
@@ -20,3 +52,7 @@ pub use event::Event;
 pub use toast::Toast;
 /// Toast message kinds
 pub use toast::ToastKind;
+
+/// Backend user role type module
+mod user_role;
+pub use user_role::UserRole;
@@ -0,0 +1,151 @@
+//-- ./src/domain/screen.rs
+
+// #![allow(unused)] // For development only
+
+//! The screens (routes) the TUI can show in the body
+//! ---
+
+use core::{fmt, str};
+
+use crate::prelude::*;
+
+/// Application screen shown in the body
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Screen {
+    /// Log in with an email and password
+    #[default]
+    Login,
+
+    /// Register a new account
+    Register,
+
+    /// Request a password reset token be emailed
+    ForgotPassword,
+
+    /// Set a new password using an emailed reset token
+    ResetPassword,
+
+    /// Landing screen once logged in
+    Home,
+
+    /// The most recent logins, beside the detail of the selected login
+    Logins,
+
+    /// Security events raised by the logins watcher
+    Security,
+
+    /// Audit log of administrative actions
+    AuditLog,
+
+    /// TUI build and backend version information, logged in or not
+    About,
+}
+
+impl Screen {
+    /// Screens shown as tabs when not logged in
+    const LOGGED_OUT_TABS: [Screen; 5] = [
+        Screen::Login,
+        Screen::Register,
+        Screen::ForgotPassword,
+        Screen::ResetPassword,
+        Screen::About,
+    ];
+
+    /// Screens shown as tabs when logged in
+    const LOGGED_IN_TABS: [Screen; 5] = [
+        Screen::Home,
+        Screen::Logins,
+        Screen::Security,
+        Screen::AuditLog,
+        Screen::About,
+    ];
+
+    /// The screens shown in the tab bar, in order
+    pub fn tabs(is_logged_in: bool) -> &'static [Screen] {
+        if is_logged_in {
+            &Self::LOGGED_IN_TABS
+        } else {
+            &Self::LOGGED_OUT_TABS
+        }
+    }
+
+    /// Every screen, in tab bar order, each once
+    pub fn all() -> impl Iterator<Item = Screen> {
+        Self::LOGGED_OUT_TABS.into_iter().chain(
+            Self::LOGGED_IN_TABS
+                .into_iter()
+                .filter(|screen| !Self::LOGGED_OUT_TABS.contains(screen)),
+        )
+    }
+
+    /// Does the screen need the operator to be logged in
+    pub fn requires_login(&self) -> bool {
+        !Self::LOGGED_OUT_TABS.contains(self)
+    }
+
+    /// Does the screen need the operator to be logged out, i.e. the forms
+    pub fn requires_logout(&self) -> bool {
+        !Self::LOGGED_IN_TABS.contains(self)
+    }
+
+    /// Can the screen be shown, logged in or not
+    pub fn is_available(&self, is_logged_in: bool) -> bool {
+        if is_logged_in {
+            !self.requires_logout()
+        } else {
+            !self.requires_login()
+        }
+    }
+
+    /// Does the screen show a table, that list actions and counts go to
+    pub fn has_list(&self) -> bool {
+        matches!(self, Screen::Logins | Screen::Security | Screen::AuditLog)
+    }
+
+    /// Does the screen show the detail of the selected row beside its table,
+    /// that split pane actions go to
+    pub fn has_detail(&self) -> bool {
+        matches!(self, Screen::Logins)
+    }
+
+    /// Is the screen only available to operators with the Admin role
+    pub fn is_admin_only(&self) -> bool {
+        matches!(self, Screen::Logins | Screen::Security | Screen::AuditLog)
+    }
+}
+
+impl fmt::Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Screen::Login => write!(f, "Login"),
+            Screen::Register => write!(f, "Register"),
+            Screen::ForgotPassword => write!(f, "Forgot Password"),
+            Screen::ResetPassword => write!(f, "Reset Password"),
+            Screen::Home => write!(f, "Home"),
+            Screen::Logins => write!(f, "Logins"),
+            Screen::Security => write!(f, "Security"),
+            Screen::AuditLog => write!(f, "Audit Log"),
+            Screen::About => write!(f, "About"),
+        }
+    }
+}
+
+/// Parse a screen from its variant name, as used in the key bindings config
+impl str::FromStr for Screen {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "Login" => Ok(Screen::Login),
+            "Register" => Ok(Screen::Register),
+            "ForgotPassword" => Ok(Screen::ForgotPassword),
+            "ResetPassword" => Ok(Screen::ResetPassword),
+            "Home" => Ok(Screen::Home),
+            "Logins" => Ok(Screen::Logins),
+            "Security" => Ok(Screen::Security),
+            "AuditLog" => Ok(Screen::AuditLog),
+            "About" => Ok(Screen::About),
+            _ => Err(Error::Keybinding(format!("unknown screen `{s}`"))),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//-- ./src/domain/security_event.rs
+
+// #![allow(unused)] // For development only
+
+//! Define the security event domain, raised by the logins watcher
+//! ---
+
+use std::{net, time};
+
+/// Enum list of security event kinds
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum SecurityEventKind {
+    /// A login came from an IP address never seen before for the account
+    NewLoginIp,
+}
+
+/// Security event type structure
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecurityEvent {
+    /// What kind of security event this is
+    pub kind: SecurityEventKind,
+
+    /// The user account the event relates to
+    pub user_id: String,
+
+    /// The IP address the login came from
+    pub login_ip: net::IpAddr,
+
+    /// When the login happened, as formatted by the backend
+    pub login_on: String,
+
+    /// When the TUI detected the event
+    pub detected_at: time::SystemTime,
+}
+
+impl SecurityEvent {
+    /// Create a new login from new IP security event
+    pub fn new_login_ip(
+        user_id: impl Into<String>,
+        login_ip: net::IpAddr,
+        login_on: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind: SecurityEventKind::NewLoginIp,
+            user_id: user_id.into(),
+            login_ip,
+            login_on: login_on.into(),
+            detected_at: time::SystemTime::now(),
+        }
+    }
+
+    /// Human readable description of the event
+    pub fn message(&self) -> String {
+        match self.kind {
+            SecurityEventKind::NewLoginIp => {
+                format!("New login IP {} for user {}", self.login_ip, self.user_id)
+            }
+        }
+    }
+}
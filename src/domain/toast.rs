@@ -7,8 +7,10 @@
 
 use std::time;
 
+use crate::domain;
+
 /// Enum list of Toast message kinds
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum::Display)]
 pub enum ToastKind {
     Error,
     Info,
@@ -28,6 +30,12 @@ pub struct Toast {
 
     /// Set the instant in time the toast message was shown
     pub(crate) shown_at: time::Instant,
+
+    /// How many times the same toast message was raised while it was shown
+    pub(crate) count: usize,
+
+    /// Label and action the operator can trigger from the toast, i.e. Retry
+    pub(crate) follow_up: Option<(String, Box<domain::Action>)>,
 }
 
 impl Toast {
@@ -41,6 +49,8 @@ impl Toast {
             kind,
             message,
             shown_at,
+            count: 1,
+            follow_up: None,
         }
     }
 
@@ -50,11 +60,20 @@ impl Toast {
         self
     }
 
+    /// Offer an action the operator can trigger from the toast
+    pub fn follow_up(mut self, label: impl Into<String>, action: domain::Action) -> Self {
+        self.follow_up = Some((label.into(), Box::new(action)));
+        self
+    }
+
+    /// Is this the same message as another toast, so they can be merged
+    pub fn is_same_message(&self, other: &Toast) -> bool {
+        self.kind == other.kind && self.message == other.message
+    }
+
     /// Set the time shown to now
     pub fn shown_now(mut self) -> Self {
         self.shown_at = time::Instant::now();
         self
     }
 }
-
-//TODO: Write some tests
@@ -0,0 +1,71 @@
+//-- ./src/domain/token_claims.rs
+
+// #![allow(unused)] // For development only
+
+//! Define the claims carried in the backend JSON Web Tokens
+//! ---
+
+use base64::Engine;
+
+use crate::prelude::*;
+
+/// JWT claims used by the TUI. The token signature is not validated, the
+/// backend remains the authority on what a token permits.
+#[derive(Debug, Clone, PartialEq, Default, serde::Deserialize)]
+pub struct TokenClaims {
+    /// Subject of the token, the user id
+    pub sub: Option<String>,
+
+    /// Role of the user the token was issued to
+    #[serde(alias = "rol")]
+    pub role: Option<String>,
+
+    /// Expiry time of the token, as seconds since the Unix epoch
+    pub exp: Option<i64>,
+}
+
+impl TokenClaims {
+    /// Decode the claims from the payload segment of a JWT
+    pub fn decode(token: &str) -> Result<Self> {
+        let payload = token
+            .split('.')
+            .nth(1)
+            .ok_or(Error::Static("Token is not a JSON Web Token"))?;
+
+        let payload =
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(payload)?;
+        let claims = serde_json::from_slice(&payload)?;
+
+        Ok(claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[test]
+    fn decode_token_claims() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let payload = r#"{"sub":"user-id","rol":"Admin","exp":1700000000}"#;
+        let payload =
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(payload);
+        let token = format!("header.{payload}.signature");
+
+        //-- Execute Function (Act)
+        let claims = TokenClaims::decode(&token)?;
+
+        //-- Checks (Assertions)
+        assert_eq!(claims.sub.as_deref(), Some("user-id"));
+        assert_eq!(claims.role.as_deref(), Some("Admin"));
+        assert_eq!(claims.exp, Some(1700000000));
+
+        //-- Return
+        Ok(())
+    }
+}
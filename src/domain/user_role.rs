@@ -0,0 +1,50 @@
+//-- ./src/domain/user_role.rs
+
+// #![allow(unused)] // For development only
+
+//! The roles a logged in operator can have on the backend
+//! ---
+
+use core::fmt;
+
+use crate::domain;
+
+/// Backend user role
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub enum UserRole {
+    /// Can manage users, sessions and logins
+    Admin,
+
+    /// Can manage their own account
+    User,
+
+    /// Not logged in or role unknown
+    #[default]
+    Guest,
+}
+
+impl UserRole {
+    /// Parse a role from the backend, treating unknown roles as a guest
+    pub fn parse(role: &str) -> Self {
+        match role.to_lowercase().as_str() {
+            "admin" => UserRole::Admin,
+            "user" => UserRole::User,
+            _ => UserRole::Guest,
+        }
+    }
+
+    /// Is this role permitted to dispatch the action
+    pub fn is_permitted(&self, action: &domain::Action) -> bool {
+        !action.is_admin_only() || *self == UserRole::Admin
+    }
+}
+
+impl fmt::Display for UserRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UserRole::Admin => write!(f, "Admin"),
+            UserRole::User => write!(f, "User"),
+            UserRole::Guest => write!(f, "Guest"),
+        }
+    }
+}
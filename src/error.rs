@@ -23,6 +23,18 @@ pub enum Error {
     #[error("Static error: {0}")]
     Static(&'static str),
 
+    /// Invalid key binding in the configuration file
+    #[error("Key binding error: {0}")]
+    Keybinding(String),
+
+    /// Invalid theme in the configuration or theme file
+    #[error("Theme error: {0}")]
+    Theme(String),
+
+    /// Backend version that can not be parsed
+    #[error("Backend version error: {0}")]
+    Version(String),
+
     //-- External errors
     /// Derive IO errors
     #[error(transparent)]
@@ -46,6 +58,15 @@ pub enum Error {
     #[error(transparent)]
     TonicUri(#[from] tonic::codegen::http::uri::InvalidUri),
 
+    #[error(transparent)]
+    TonicMetadata(#[from] tonic::metadata::errors::InvalidMetadataValue),
+
+    #[error(transparent)]
+    SerdeJson(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Base64Decode(#[from] base64::DecodeError),
+
     #[error(transparent)]
     ActionSend(#[from] tokio::sync::mpsc::error::SendError<crate::domain::Action>),
 
@@ -54,6 +75,26 @@ pub enum Error {
     // tokio::sync::mpsc::error::TryRecvError
 }
 
+impl Error {
+    /// The gRPC status code of the error, for errors that did not come back
+    /// from the backend the status is unavailable
+    pub fn rpc_code(&self) -> tonic::Code {
+        match self {
+            Error::TonicStatus(status) => status.code(),
+            _ => tonic::Code::Unavailable,
+        }
+    }
+
+    /// A message suitable for showing to the operator, using the backend
+    /// status message where there is one
+    pub fn rpc_message(&self) -> String {
+        match self {
+            Error::TonicStatus(status) => status.message().to_string(),
+            error => error.to_string(),
+        }
+    }
+}
+
 pub fn init(tick_rate: f64, frame_rate: f64) -> Result<()> {
     let (panic_hook, eyre_hook) = color_eyre::config::HookBuilder::default()
         .panic_section(format!(
@@ -66,7 +107,12 @@ pub fn init(tick_rate: f64, frame_rate: f64) -> Result<()> {
         .into_hooks();
     eyre_hook.install()?;
     std::panic::set_hook(Box::new(move |panic_info| {
-        if let Ok(mut t) = crate::Terminal::new(tick_rate, frame_rate) {
+        // Match the terminal capture enabled in App::run so restore disables it.
+        // Mouse capture depends on the config, which the hook does not have,
+        // and disabling it when it was never enabled does no harm.
+        let terminal = crate::Terminal::new(tick_rate, frame_rate)
+            .map(|t| t.enable_mouse(true).enable_paste(true));
+        if let Ok(mut t) = terminal {
             if let Err(r) = t.restore() {
                 tracing::error!("Unable to exit Terminal: {:?}", r);
             }
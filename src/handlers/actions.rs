@@ -8,7 +8,7 @@
 
 use tokio::sync::mpsc;
 
-use crate::{domain, handlers, prelude::*};
+use crate::{domain, handlers, prelude::*, state, ui};
 
 // #[derive(Debug)]
 pub struct ActionHandler {
@@ -20,30 +20,39 @@ pub struct ActionHandler {
 
     /// Map key events to actions.
     keys: crate::handlers::KeyEventHandler,
+
+    /// Map mouse events to actions.
+    mouse: crate::handlers::MouseEventHandler,
 }
 
-impl Default for ActionHandler {
-    /// Default settings used to write to file if config file not found
-    fn default() -> Self {
+impl ActionHandler {
+    /// Construct the action channels, mapping keys with the keymap
+    pub fn new(keymap: domain::Keymap) -> Self {
         // Initiate send receive event channels
         let (sender, receiver) = mpsc::unbounded_channel();
 
-        let keys = handlers::KeyEventHandler::default();
+        let keys = handlers::KeyEventHandler::new(keymap);
+        let mouse = handlers::MouseEventHandler::default();
 
         Self {
             action_sender: sender,
             action_receiver: receiver,
             keys,
+            mouse,
         }
     }
 }
 
 impl ActionHandler {
     /// Transform an application (terminal) event into an Action an then add to the que.
+    /// Digits are a count for list actions if the screen `accepts_count`.
     pub async fn handle_events(
         &mut self,
         app_mode: domain::AppMode,
+        accepts_count: bool,
+        mouse_areas: &state::MouseState,
         terminal_events: &mut handlers::CrosstermEventsHandler,
+        components: &mut ui::components::Components,
     ) -> Result<()> {
         // Clone the task sender channel
         let action_sender = self.action_sender.clone();
@@ -53,20 +62,51 @@ impl ActionHandler {
             return Ok(());
         };
 
+        // The keys pending before the event, to tell the footer of changes
+        let pending_label = self.keys.pending_label();
+
         // Match the event to an Action
         let action = match event {
             // crate::handlers::event::Event::Closed => todo!(),
             // handlers::Event::Error => Action::Error,
             domain::Event::Init => domain::Action::Init,
-            domain::Event::FocusGained => domain::Action::Resume,
-            domain::Event::FocusLost => domain::Action::Resume,
-            domain::Event::Key(key) => self.keys.handle_event(app_mode, key),
-            // crate::handlers::event::Event::Mouse(_) => todo!(),
+            domain::Event::FocusGained => domain::Action::Focus(true),
+            domain::Event::FocusLost => domain::Action::Focus(false),
+            domain::Event::Key(key) => {
+                // Offer the key to the components first, then the key map
+                let mut component_action = None;
+                for component in components.iter_mut() {
+                    component_action = component.handle_key(key)?;
+                    if component_action.is_some() {
+                        break;
+                    }
+                }
+                component_action.unwrap_or_else(|| {
+                    self.keys.handle_event(app_mode.clone(), key, accepts_count)
+                })
+            }
+            domain::Event::Mouse(mouse) => {
+                // Offer the mouse event to the components first, then the
+                // clickable areas of the last frame
+                let mut component_action = None;
+                for component in components.iter_mut() {
+                    component_action = component.handle_mouse(mouse)?;
+                    if component_action.is_some() {
+                        break;
+                    }
+                }
+                component_action
+                    .unwrap_or_else(|| self.mouse.handle_event(mouse_areas, mouse))
+            }
             domain::Event::Paste(s) => domain::Action::Paste(s),
             domain::Event::Quit => domain::Action::Quit,
             domain::Event::Render => domain::Action::Render,
             domain::Event::Resize(x, y) => domain::Action::Resize(x, y),
-            domain::Event::Tick => domain::Action::Tick,
+            domain::Event::Tick => {
+                // Drop a timed out key sequence or count
+                self.keys.expire();
+                domain::Action::Tick
+            }
             // Every other event to Nil
             _ => domain::Action::Nil,
         };
@@ -74,6 +114,12 @@ impl ActionHandler {
         // Send action to the que
         action_sender.send(action)?;
 
+        // Show the count and keys pending in the footer
+        let new_pending_label = self.keys.pending_label();
+        if new_pending_label != pending_label {
+            action_sender.send(domain::Action::PendingKeys(new_pending_label))?;
+        }
+
         Ok(())
     }
 
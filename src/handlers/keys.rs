@@ -3,52 +3,244 @@
 // #![allow(unused)] // For beginning only.
 
 //! What to do with key events
+//!
+//! Keys are looked up in the keymap, waiting for the rest of a key sequence
+//! when a key starts one. In normal mode on screens with a list, digits build
+//! a count for the next list action, i.e. `5j`, rather than jump to a tab. An
+//! incomplete sequence or count is dropped once the keymap timeout passes
+//! without another key.
 //! ---
 
-use crate::domain;
+use std::time;
+
 use crossterm::event as crossterm;
 
+use crate::domain;
+
+/// The largest count, so a held down digit can not queue endless actions
+const MAX_COUNT: usize = 9999;
+
+/// Map key events to actions using the configured keymap, waiting for the rest
+/// of a key sequence when a key starts one
 #[derive(Debug, Clone, PartialEq, Default)]
-pub struct KeyEventHandler {}
+pub struct KeyEventHandler {
+    /// Key bindings for each application mode
+    keymap: domain::Keymap,
+
+    /// The chords pressed so far of a key sequence
+    pending: Vec<domain::KeyChord>,
+
+    /// The count typed before the key sequence, if any
+    count: Option<usize>,
+
+    /// When the last key of an incomplete sequence or count was pressed
+    pending_since: Option<time::Instant>,
+}
 
 impl KeyEventHandler {
+    /// Construct a key event handler using the keymap
+    pub fn new(keymap: domain::Keymap) -> Self {
+        Self {
+            keymap,
+            ..Self::default()
+        }
+    }
+
+    /// The count and keys pressed so far, empty if nothing is pending
+    pub fn pending_label(&self) -> String {
+        let count = self
+            .count
+            .map(|count| count.to_string())
+            .unwrap_or_default();
+        let keys: Vec<String> =
+            self.pending.iter().map(ToString::to_string).collect();
+
+        format!("{count}{}", keys.join(" "))
+    }
+
     /// Handles the key events and updates the state of [`App`].
-    pub fn handle_event(&self, app_mode: domain::AppMode, key_event: crossterm::KeyEvent) -> domain::Action {
-        match app_mode {
-            domain::AppMode::Normal => match key_event.code {
-                // Exit application on `q`
-                crossterm::KeyCode::Char('q') => domain::Action::Quit,
-
-                // // Test toast message with 't'
-                // crossterm::KeyCode::Char('t') => {
-                //     let toast_message = "I am toasty".to_string();
-                //     let toast = domain::Toast::new(toast_message);
-                //     domain::Action::Toast(toast)
-                // },
-
-                // Update backend server status
-                crossterm::KeyCode::Char('u') => domain::Action::BackendStatusUpdate,
-
-                // Update backend server status
-                crossterm::KeyCode::Char('m') => domain::Action::AppMode(domain::AppMode::Input),
-
-                // Escape from the tui application
-                crossterm::KeyCode::Esc => domain::Action::ClearToast,
-
-                // All other key events have nil action
-                _ => domain::Action::Nil,
-            },
-            domain::AppMode::Input => match key_event.code  {
-                // let toasty = domain::Toast::new("Edit mode");
-                // domain::Action::Toast(toasty)
-
-                // Escape from the tui application
-                crossterm::KeyCode::Esc => domain::Action::AppMode(domain::AppMode::Normal),
-
-                // Add to input
-                _ => domain::Action::Nil,
-
-            },
+    pub fn handle_event(
+        &mut self,
+        app_mode: domain::AppMode,
+        key_event: crossterm::KeyEvent,
+        accepts_count: bool,
+    ) -> domain::Action {
+        let chord = domain::KeyChord::from(key_event);
+        self.pending_since = Some(time::Instant::now());
+
+        //-- 1. Build a count from digits typed before a key sequence
+        let is_counting = app_mode == domain::AppMode::Normal
+            && accepts_count
+            && self.pending.is_empty();
+        if is_counting {
+            if let Some(digit) = count_digit(&chord, self.count.is_some()) {
+                let count = self.count.unwrap_or(0) * 10 + digit;
+                self.count = Some(count.min(MAX_COUNT));
+                return domain::Action::Nil;
+            }
+        }
+
+        //-- 2. Look up the key sequence
+        self.pending.push(chord);
+
+        match self.keymap.lookup(&app_mode, &self.pending) {
+            domain::KeyLookup::Action(action) => {
+                let count = self.count.take();
+                self.clear();
+                with_count(action, count)
+            }
+
+            // Wait for the next key of the sequence
+            domain::KeyLookup::Pending => domain::Action::Nil,
+
+            domain::KeyLookup::Unbound => {
+                // A broken sequence, try again with only the last key
+                if self.pending.len() > 1 {
+                    self.pending.clear();
+                    return self.handle_event(app_mode, key_event, accepts_count);
+                }
+                self.clear();
+
+                match app_mode {
+                    // All other key events have nil action
+                    domain::AppMode::Normal => domain::Action::Nil,
+
+                    // Add to input
+                    domain::AppMode::Input => domain::Action::FormInput(key_event),
+                }
+            }
         }
     }
+
+    /// Drop an incomplete key sequence or count once the keymap timeout has
+    /// passed, returning true if anything was dropped
+    pub fn expire(&mut self) -> bool {
+        let timeout = self.keymap.timeout();
+        let is_expired = self
+            .pending_since
+            .is_some_and(|pending_since| pending_since.elapsed() >= timeout);
+        if is_expired {
+            self.clear();
+        }
+
+        is_expired
+    }
+
+    /// Forget the keys pressed so far
+    fn clear(&mut self) {
+        self.pending.clear();
+        self.count = None;
+        self.pending_since = None;
+    }
+}
+
+/// The digit of an unmodified digit key. A zero only continues a count.
+fn count_digit(chord: &domain::KeyChord, is_counting: bool) -> Option<usize> {
+    if !chord.modifiers.is_empty() {
+        return None;
+    }
+    let crossterm::KeyCode::Char(c) = chord.code else {
+        return None;
+    };
+
+    match c.to_digit(10)? {
+        0 if !is_counting => None,
+        digit => Some(digit as usize),
+    }
+}
+
+/// Apply a count to an action, as vim does. A count before `G` selects that
+/// row, other list actions are repeated and the rest ignore the count.
+fn with_count(action: domain::Action, count: Option<usize>) -> domain::Action {
+    match (action, count) {
+        (domain::Action::ListLast, Some(count)) => {
+            domain::Action::ListSelect(count.saturating_sub(1))
+        }
+        (action, Some(count)) if action.is_repeatable() && count > 1 => {
+            domain::Action::Repeat(count, Box::new(action))
+        }
+        (action, _) => action,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+    use crate::config;
+
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    /// A key press without modifiers
+    fn key(c: char) -> crossterm::KeyEvent {
+        crossterm::KeyEvent::new(
+            crossterm::KeyCode::Char(c),
+            crossterm::KeyModifiers::NONE,
+        )
+    }
+
+    #[test]
+    fn counts_and_sequences_build_list_actions() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let keymap = domain::Keymap::new(&config::KeybindingsConfig::default())?;
+        let mut keys = KeyEventHandler::new(keymap);
+        let normal = domain::AppMode::Normal;
+
+        //-- Execute Function (Act)
+        let counted: Vec<domain::Action> = "12jgg5G"
+            .chars()
+            .map(|c| keys.handle_event(normal.clone(), key(c), true))
+            .collect();
+        let tab = keys.handle_event(normal.clone(), key('2'), false);
+
+        //-- Checks (Assertions)
+        assert_eq!(
+            counted[2],
+            domain::Action::Repeat(12, Box::new(domain::Action::ListNext))
+        );
+        assert_eq!(counted[4], domain::Action::ListFirst);
+        assert_eq!(counted[6], domain::Action::ListSelect(4));
+        assert_eq!(tab, domain::Action::NavigateTab(1));
+        assert_eq!(keys.pending_label(), "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn timed_out_counts_and_sequences_are_dropped() -> Result<()> {
+        //-- Setup and Fixtures (Arrange)
+        let mut config = config::KeybindingsConfig::default();
+        let waiting = domain::Keymap::new(&config)?;
+        config.sequence_timeout_ms = 0;
+        let timed_out = domain::Keymap::new(&config)?;
+        let normal = domain::AppMode::Normal;
+        let alt_tab = crossterm::KeyEvent::new(
+            crossterm::KeyCode::Char('2'),
+            crossterm::KeyModifiers::ALT,
+        );
+
+        //-- Execute Function (Act)
+        let mut keys = KeyEventHandler::new(waiting);
+        keys.handle_event(normal.clone(), key('5'), true);
+        let is_kept = !keys.expire();
+        let kept_label = keys.pending_label();
+
+        let mut keys = KeyEventHandler::new(timed_out);
+        let count = keys.handle_event(normal.clone(), key('5'), true);
+        keys.handle_event(normal.clone(), key('g'), true);
+        let is_dropped = keys.expire();
+        let tab = keys.handle_event(normal.clone(), alt_tab, true);
+
+        //-- Checks (Assertions)
+        assert!(is_kept);
+        assert_eq!(kept_label, "5");
+        assert_eq!(count, domain::Action::Nil);
+        assert!(is_dropped);
+        assert_eq!(keys.pending_label(), "");
+        assert!(!keys.expire());
+        assert_eq!(tab, domain::Action::NavigateTab(1));
+
+        Ok(())
+    }
 }
@@ -4,8 +4,9 @@
 //! 
 //! 1. Crossterm: Handle crossterm backend terminal events
 //! 2. Keys: Handle key events
-//! 3. Render: Handle backend frame render events
-//! 4. Tick: Handle regular application tick events
+//! 3. Mouse: Handle mouse events
+//! 4. Render: Handle backend frame render events
+//! 5. Tick: Handle regular application tick events
 
 mod actions;
 pub use actions::ActionHandler;
@@ -19,6 +20,10 @@ mod keys;
 // pub use keys::handle_event;
 pub use keys::KeyEventHandler;
 
+/// Handle mouse events
+mod mouse;
+pub use mouse::MouseEventHandler;
+
 /// Handle render events
 mod render;
 pub use render::RenderEventHandler;
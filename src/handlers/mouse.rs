@@ -0,0 +1,48 @@
+//-- ./src/handlers/mouse.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! What to do with mouse events not consumed by a component
+//! ---
+
+use crossterm::event as crossterm;
+
+use crate::{domain, state};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MouseEventHandler {}
+
+impl MouseEventHandler {
+    /// Map a mouse event to an action, using the clickable areas registered
+    /// while rendering the last frame
+    pub fn handle_event(
+        &self,
+        mouse_areas: &state::MouseState,
+        mouse_event: crossterm::MouseEvent,
+    ) -> domain::Action {
+        match mouse_event.kind {
+            // Click on a tab or table row
+            crossterm::MouseEventKind::Down(crossterm::MouseButton::Left) => {
+                match mouse_areas.target_at(mouse_event.column, mouse_event.row) {
+                    Some(state::MouseTarget::Tab(index)) => {
+                        domain::Action::NavigateTab(index)
+                    }
+                    Some(state::MouseTarget::TableRow(row)) => {
+                        domain::Action::ListSelect(row)
+                    }
+                    Some(state::MouseTarget::SplitDetail) => {
+                        domain::Action::SplitFocus
+                    }
+                    None => domain::Action::Nil,
+                }
+            }
+
+            // Scroll the table being shown
+            crossterm::MouseEventKind::ScrollDown => domain::Action::ListNext,
+            crossterm::MouseEventKind::ScrollUp => domain::Action::ListPrevious,
+
+            // All other mouse events have nil action
+            _ => domain::Action::Nil,
+        }
+    }
+}
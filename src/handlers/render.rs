@@ -4,7 +4,10 @@
 
 //! Application frame render events
 //!
-//! The frequency of this event is set in config and triggered in CrosstermEventsHandler
+//! The frequency of this event is set in config and triggered in CrosstermEventsHandler.
+//! A frame is only drawn when the state is dirty, or once a second so
+//! countdowns move on, and no faster than the idle frame rate while the
+//! terminal is out of focus.
 //! ---
 
 use std::time;
@@ -13,93 +16,127 @@ use tokio::sync::mpsc;
 
 use crate::{domain, prelude::*, state, ui, Terminal};
 
-/// Keep track of the render event cycles
+/// Render the TUI each render event cycle
 #[derive(Debug, Clone)]
 pub struct RenderEventHandler {
     /// Application configuration
     config: crate::config::Config,
 
+    /// Named styles to render with
+    theme: ui::Theme,
+
     /// Action sender
     #[allow(unused)]
     action_sender: mpsc::UnboundedSender<domain::Action>,
 
-    /// Time since last frame
-    last_frame_update: time::Instant,
-
-    /// The number of frames
-    frame_count: u32,
+    /// Does the terminal have focus
+    is_focused: bool,
 
-    /// Rolling frames per second calculation
-    frames_per_second: f64,
+    /// When the last frame was drawn
+    last_drawn: time::Instant,
 }
 
+/// Draw at least this often, so countdowns and expiring toasts move on
+const REFRESH_INTERVAL: time::Duration = time::Duration::from_secs(1);
+
 impl RenderEventHandler {
-    /// New TickEventHandler instance
+    /// New RenderEventHandler instance
     pub fn init(
         config: crate::config::Config,
+        theme: ui::Theme,
         action_sender: mpsc::UnboundedSender<domain::Action>,
     ) -> Self {
-        let last_frame_update = time::Instant::now();
-        let frame_count = 0;
-        let frames_per_second = 0.0;
         Self {
             config,
+            theme,
             action_sender,
-            last_frame_update,
-            frame_count,
-            frames_per_second,
+            is_focused: true,
+            last_drawn: time::Instant::now(),
         }
     }
 
-    /// What to do each render event cycle
+    /// Track the terminal focus, throttling frames while out of focus
+    pub fn set_focus(&mut self, is_focused: bool) {
+        self.is_focused = is_focused;
+    }
+
+    /// What to do each render event cycle, skipping the frame unless it is due
     pub fn handle_event(
         &mut self,
         state: &mut state::State,
+        components: &mut ui::components::Components,
         terminal: &mut Terminal,
     ) {
-        // Calculate the frame rate based on the last render event
-        self.calculate_frame_rate();
-
-        // Update the application state
-        state.app.frames_per_second = self.frames_per_second;
-
-        let _ = self.render_tui(state, terminal);
-    }
-
-    /// Update frame rate per second
-    fn calculate_frame_rate(&mut self) {
-        // Increment the frame count by 1
-        self.frame_count += 1;
-
-        // Represent now as an Instance
-        let now = time::Instant::now();
-
-        // Calculate elapsed time since the last frame
-        let elapsed = (now - self.last_frame_update).as_secs_f64();
-
-        // if the last frame elapsed time is grater than up update the component state
-        if elapsed >= 1.0 {
-            self.frames_per_second = self.frame_count as f64 / elapsed;
-            self.last_frame_update = now;
-            self.frame_count = 0;
+        let idle_interval = time::Duration::from_secs_f64(
+            1.0 / self.config.app.idle_frame_rate.max(f64::EPSILON),
+        );
+        let is_due = is_frame_due(
+            state.app.is_dirty,
+            self.is_focused,
+            self.last_drawn.elapsed(),
+            idle_interval,
+        );
+        if !is_due {
+            components.statistics.skip_frame();
+            return;
         }
-    }
 
-    /// Return the current application frame rate
-    pub fn frame_rate(self) -> f64 {
-        self.frames_per_second
+        state.app.is_dirty = false;
+        self.last_drawn = time::Instant::now();
+        let _ = self.render_tui(state, components, terminal);
     }
 
     /// Render the Terminal User Interface (TUI)
     fn render_tui(
         &mut self,
         state: &mut crate::state::State,
+        components: &mut ui::components::Components,
         terminal: &mut Terminal,
     ) -> Result<()> {
         terminal.draw(|frame| {
-            ui::layout::render(self.config.clone(), state, frame)
+            ui::layout::render(self.config.clone(), &self.theme, state, components, frame)
         })?;
 
         Ok(())
     }
 }
+
+/// Is a frame due, the state being dirty or the refresh interval passing.
+/// Out of focus frames are no closer together than the idle interval.
+fn is_frame_due(
+    is_dirty: bool,
+    is_focused: bool,
+    since_drawn: time::Duration,
+    idle_interval: time::Duration,
+) -> bool {
+    let is_changed = is_dirty || since_drawn >= REFRESH_INTERVAL;
+
+    is_changed && (is_focused || since_drawn >= idle_interval)
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn frames_are_drawn_when_dirty_and_throttled_out_of_focus() {
+        //-- Setup and Fixtures (Arrange)
+        let frame = time::Duration::from_millis(16);
+        let idle = time::Duration::from_secs(2);
+
+        //-- Execute Function (Act)
+        let clean = is_frame_due(false, true, frame, idle);
+        let dirty = is_frame_due(true, true, frame, idle);
+        let refresh = is_frame_due(false, true, REFRESH_INTERVAL, idle);
+        let unfocused = is_frame_due(true, false, frame, idle);
+        let unfocused_idle = is_frame_due(true, false, idle, idle);
+
+        //-- Checks (Assertions)
+        assert!(!clean);
+        assert!(dirty);
+        assert!(refresh);
+        assert!(!unfocused);
+        assert!(unfocused_idle);
+    }
+}
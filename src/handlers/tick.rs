@@ -5,75 +5,47 @@
 //! Application tick events
 //!
 //! The frequency of this event is set in config and triggered in CrosstermEventsHandler
+//!
+//! The backend status check and logins watch intervals are set in config too.
 //! ---
 
-// use std::{os::unix::net, time};
-use std::time;
 use tokio::sync::mpsc;
 
-use crate::{domain, prelude::*, state};
-
-const TOAST_DURATION: time::Duration = time::Duration::from_secs(3);
-const STATUS_CHECK_DURATION: time::Duration = time::Duration::from_secs(10 * 60); // Every 10 minutes
+use crate::{config, domain, prelude::*, state};
 
 #[derive(Debug, Clone)]
 pub struct TickEventHandler {
+    /// Status check and logins watch intervals
+    config: config::AppConfig,
+
     /// Action sender
     action_sender: mpsc::UnboundedSender<domain::Action>,
-
-    /// Time since last tick
-    last_tick_update: time::Instant,
-
-    /// The number of ticks
-    tick_count: u32,
-
-    /// Rolling ticks per second calculation
-    ticks_per_second: f64,
 }
 
 impl TickEventHandler {
     /// New TickEventHandler instance
-    pub fn init(action_sender: mpsc::UnboundedSender<domain::Action>) -> Self {
-        let last_tick_update = time::Instant::now();
-        let tick_count = 0;
-        let ticks_per_second = 0.0;
+    pub fn init(
+        config: config::AppConfig,
+        action_sender: mpsc::UnboundedSender<domain::Action>,
+    ) -> Self {
         Self {
+            config,
             action_sender,
-            last_tick_update,
-            tick_count,
-            ticks_per_second,
         }
     }
 
     /// What to do each tick event cycle
     pub async fn handle_event(&mut self, state: &mut state::State) -> Result<()> {
-        //-- 1. Calculate tick statistics
-        // Calculate the current tick rate, based on the last tick time
-        self.calculate_tick_rate();
-
-        // Update the application tick rate state
-        state.app.ticks_per_second = self.ticks_per_second;
-
-        //-- 2. Manage toast messages
-        // If we have an optional toast message wait for elapsed time to exceed
-        if let Some(ref mut t) = state.toast.current {
-            // If toast duration is exceeded set option to None, to display
-            // the next toast message in the queue.
-            if t.shown_at.elapsed() > TOAST_DURATION {
-                state.toast.current = None;
-            }
-        // Else if we have None optional toast and there is something in
-        // the toast queue pop it into the optional toast
-        } else if let Some(mut t) = state.toast.queue.pop_front() {
-            t.shown_at = time::Instant::now();
-            state.toast.current = Some(t);
-        }
-
-        //-- 3. Request backend status update
+        //-- 1. Request backend status update
         // If the status has been checked previously (not None), check if status
-        // check duration has elapsed
+        // check interval has elapsed, checking sooner while offline or degraded,
+        // or while the home screen shows the backend health panel
         if let Some(checked_on) = state.backend.status_checked_on {
-            if checked_on.elapsed() > STATUS_CHECK_DURATION {
+            let is_unhealthy = state.backend.status.is_unhealthy();
+            let is_health_shown = state.navigation.current == domain::Screen::Home;
+            let interval =
+                self.config.status_check_interval(is_unhealthy, is_health_shown);
+            if checked_on.elapsed() > interval {
                 self.action_sender
                     .send(domain::Action::BackendStatusUpdate)?;
             }
@@ -83,31 +55,30 @@ impl TickEventHandler {
                 .send(domain::Action::BackendStatusUpdate)?;
         }
 
-        Ok(())
-    }
-
-    /// Update tick rate information
-    fn calculate_tick_rate(&mut self) {
-        // Increment the tick count by one
-        self.tick_count += 1;
-
-        // Represent now as an Instance
-        let now = time::Instant::now();
-
-        // Calculate elapsed time since last tick
-        let elapsed = (now - self.last_tick_update).as_secs_f64();
+        //-- 2. Request logins watch for new login IP addresses
+        // Only watch logins when we have an access token to read them with and
+        // our role is permitted to read them
+        let is_permitted =
+            state.backend.role.is_permitted(&domain::Action::LoginsWatch);
+        if state.backend.access_token.is_some() && is_permitted {
+            let is_due = match state.security.logins_checked_on {
+                Some(checked_on) => {
+                    checked_on.elapsed() > self.config.logins_watch_interval()
+                }
+                None => true,
+            };
+
+            if is_due {
+                self.action_sender.send(domain::Action::LoginsWatch)?;
+            }
+        }
 
-        // If elapsed time is grater than one second update tick state
-        if elapsed >= 1.0 {
-            self.ticks_per_second = self.tick_count as f64 / elapsed;
-            self.last_tick_update = now;
-            self.tick_count = 0;
+        //-- 3. Flag an access token that has expired
+        if state.backend.is_token_expired(chrono::Utc::now()) {
+            self.action_sender.send(domain::Action::TokenExpired)?;
         }
-    }
 
-    /// Return the current application tick rate per second
-    pub fn tick_rate(self) -> f64 {
-        self.ticks_per_second
+        Ok(())
     }
 }
 
@@ -0,0 +1,108 @@
+//-- ./src/services/authentication.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Authentication RPC service
+//! ---
+
+use crate::{client, prelude::*};
+
+/// Authentication service instance
+pub struct AuthenticationService {
+    // RPC Client
+    pub rpc_client: client::RpcClient,
+}
+
+impl AuthenticationService {
+    /// Construct a new authentication service using the defined rpc client
+    pub fn new(rpc_client: client::RpcClient) -> Self {
+        Self { rpc_client }
+    }
+
+    /// Log in to the backend authentication server, returning the access and
+    /// refresh tokens.
+    pub async fn login(
+        &mut self,
+        email: &str,
+        password: &str,
+    ) -> Result<(String, String)> {
+        // Build the rpc request message
+        let request_message = client::rpc::LoginRequest {
+            email: email.to_string(),
+            password: password.to_string(),
+        };
+
+        // Send rpc request
+        let response = self
+            .rpc_client
+            .authentication()
+            .login(request_message)
+            .await?
+            .into_inner();
+
+        Ok((response.access_token, response.refresh_token))
+    }
+
+    /// Register a new user account with the backend authentication server.
+    /// The backend emails the new user to verify their email address.
+    pub async fn register(
+        &mut self,
+        email: &str,
+        name: &str,
+        password: &str,
+    ) -> Result<()> {
+        // Build the rpc request message
+        let request_message = client::rpc::RegisterRequest {
+            email: email.to_string(),
+            name: name.to_string(),
+            password: password.to_string(),
+        };
+
+        // Send rpc request
+        self.rpc_client
+            .authentication()
+            .register(request_message)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Request the backend authentication server email a password reset token
+    /// to the email address, if it belongs to an account.
+    pub async fn request_password_reset(&mut self, email: &str) -> Result<()> {
+        // Build the rpc request message
+        let request_message = client::rpc::PasswordResetRequest {
+            email: email.to_string(),
+        };
+
+        // Send rpc request
+        self.rpc_client
+            .authentication()
+            .request_password_reset(request_message)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Set a new password using a password reset token emailed by the backend
+    /// authentication server.
+    pub async fn reset_password(
+        &mut self,
+        reset_token: &str,
+        new_password: &str,
+    ) -> Result<()> {
+        // Build the rpc request message
+        let request_message = client::rpc::ResetPasswordRequest {
+            reset_token: reset_token.to_string(),
+            new_password: new_password.to_string(),
+        };
+
+        // Send rpc request
+        self.rpc_client
+            .authentication()
+            .reset_password(request_message)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+//-- ./src/services/logins.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Logins RPC service
+//! ---
+
+use crate::{client, domain, prelude::*};
+
+/// Logins service instance
+pub struct LoginsService {
+    // RPC Client
+    pub rpc_client: client::RpcClient,
+}
+
+impl LoginsService {
+    /// Construct a new logins service using the defined rpc client
+    pub fn new(rpc_client: client::RpcClient) -> Self {
+        Self { rpc_client }
+    }
+
+    /// Get a page of login records from the backend authentication server,
+    /// using the access token for authorisation.
+    pub async fn index(
+        &mut self,
+        access_token: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<domain::Login>> {
+        // Build the rpc request message
+        let request_message = client::rpc::LoginsIndexRequest { limit, offset };
+        let request = client::authorised_request(request_message, access_token)?;
+
+        // Send rpc request
+        let response = self.rpc_client.logins().index(request).await?;
+
+        // Map the rpc login responses into domain logins
+        let logins = response
+            .into_inner()
+            .logins
+            .into_iter()
+            .map(domain::Login::from)
+            .collect();
+
+        Ok(logins)
+    }
+}
@@ -1,4 +1,11 @@
+mod authentication;
+pub use authentication::AuthenticationService;
 
+mod logins;
+pub use logins::LoginsService;
+
+mod sessions;
+pub use sessions::SessionsService;
 
 mod utilities;
-pub use utilities::UtilitiesService;
\ No newline at end of file
+pub use utilities::UtilitiesService;
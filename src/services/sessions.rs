@@ -0,0 +1,40 @@
+//-- ./src/services/sessions.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Sessions RPC service
+//! ---
+
+use crate::{client, prelude::*};
+
+/// Sessions service instance
+pub struct SessionsService {
+    // RPC Client
+    pub rpc_client: client::RpcClient,
+}
+
+impl SessionsService {
+    /// Construct a new sessions service using the defined rpc client
+    pub fn new(rpc_client: client::RpcClient) -> Self {
+        Self { rpc_client }
+    }
+
+    /// Revoke every session of the user with the email address, using the
+    /// access token for authorisation. Returns the number of sessions revoked.
+    pub async fn revoke_user(
+        &mut self,
+        access_token: &str,
+        email: &str,
+    ) -> Result<i64> {
+        // Build the rpc request message
+        let request_message = client::rpc::SessionsRevokeUserRequest {
+            email: email.to_string(),
+        };
+        let request = client::authorised_request(request_message, access_token)?;
+
+        // Send rpc request
+        let response = self.rpc_client.sessions().revoke_user(request).await?;
+
+        Ok(response.into_inner().revoked)
+    }
+}
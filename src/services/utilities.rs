@@ -2,7 +2,9 @@
 
 // #![allow(unused)] // For beginning only.
 
-use crate::client;
+use std::time;
+
+use crate::{client, prelude::*};
 
 /// Utilities service instance
 pub struct UtilitiesService {
@@ -16,28 +18,27 @@ impl UtilitiesService {
         Self { rpc_client }
     }
 
-    /// Check if the backend authentication server is online, returning true if
-    /// it is.
-    pub async fn is_online(&mut self) -> bool {
+    /// Ping the backend authentication server, returning the round trip time
+    /// if it is online and answers with an OK status.
+    pub async fn ping(&mut self) -> Result<time::Duration> {
         // Build the rpc request message
         let request_message = tonic::Request::new(client::rpc::Empty {});
 
-        // Send prc request
-        let response = match self.rpc_client.utilities().ping(request_message).await
-        {
-            Ok(response) => response,
-            Err(_) => return false,
-        };
+        // Send prc request, timing until the response is back
+        let sent_on = time::Instant::now();
+        let response = self
+            .rpc_client
+            .utilities()
+            .ping(request_message)
+            .await?;
+        let round_trip = sent_on.elapsed();
 
         let (response_metadata, _response_message, _response_extensions) =
             response.into_parts();
 
-        let grpc_status = response_metadata.get::<&str>("grpc-status");
-
-        if let Some(status) = grpc_status {
-            status == "0"
-        } else {
-            false
+        match response_metadata.get::<&str>("grpc-status") {
+            Some(status) if status == "0" => Ok(round_trip),
+            _ => Err(Error::Static("Ping answered without an OK status")),
         }
     }
 }
@@ -13,13 +13,10 @@ pub struct AppState {
     /// Is the application running
     pub is_running: bool,
 
-    /// Application tick rate per second
-    pub ticks_per_second: f64,
+    pub mode: domain::AppMode,
 
-    /// Application frame rate per second
-    pub frames_per_second: f64,
-
-    pub mode: domain::AppMode
+    /// Has anything changed since the last frame was drawn
+    pub is_dirty: bool,
 }
 
 impl Default for AppState {
@@ -35,19 +32,15 @@ impl AppState {
         // Set the new application run state to true
         let is_running = true;
 
-        // Set the new application tick rate (ticks per second) to 0.0
-        let ticks_per_second = 0.0;
-
-        // Set the new application frame rate (frames per second) to 0.0
-        let frames_per_second = 0.0;
-
         let mode = domain::AppMode::default();
 
+        // Draw the first frame
+        let is_dirty = true;
+
         Self {
             is_running,
-            ticks_per_second,
-            frames_per_second,
             mode,
+            is_dirty,
         }
     }
 }
@@ -0,0 +1,173 @@
+//-- ./src/state/audit.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Audit state module, appending administrative actions to the audit log file
+//! and holding the entries for the day being viewed
+//! ---
+
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    path,
+};
+
+use ratatui::widgets;
+
+use crate::{domain, prelude::*};
+
+/// The file, in the data directory, used to store the audit log as JSON lines
+const AUDIT_LOG_FILE: &str = "audit.jsonl";
+
+/// Append an audit entry to the audit log file as a JSON line
+pub fn append(
+    data_directory: &path::Path,
+    entry: &domain::AuditEntry,
+) -> Result<()> {
+    fs::create_dir_all(data_directory)?;
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(data_directory.join(AUDIT_LOG_FILE))?;
+
+    let line = serde_json::to_string(entry)?;
+    writeln!(file, "{line}")?;
+
+    Ok(())
+}
+
+/// Load the audit entries made on a given local day
+pub fn load_day(
+    data_directory: &path::Path,
+    day: chrono::NaiveDate,
+) -> Result<Vec<domain::AuditEntry>> {
+    let file = data_directory.join(AUDIT_LOG_FILE);
+
+    if !file.exists() {
+        return Ok(Vec::new());
+    }
+
+    let reader = io::BufReader::new(fs::File::open(file)?);
+
+    let mut entries = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+
+        // Skip lines that can not be parsed rather than failing the whole day
+        let entry: domain::AuditEntry = match serde_json::from_str(&line) {
+            Ok(entry) => entry,
+            Err(error) => {
+                tracing::error!("Error parsing audit log line: {}", error);
+                continue;
+            }
+        };
+
+        if entry.timestamp.with_timezone(&chrono::Local).date_naive() == day {
+            entries.push(entry);
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Audit log viewer state model
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditState {
+    /// The local day being viewed
+    pub day: chrono::NaiveDate,
+
+    /// The audit entries made on the day being viewed
+    pub entries: Vec<domain::AuditEntry>,
+
+    /// The audit entries table selection and scroll offset
+    pub table: widgets::TableState,
+}
+
+impl Default for AuditState {
+    fn default() -> Self {
+        let day = chrono::Local::now().date_naive();
+        let entries = Vec::new();
+        let table = widgets::TableState::default();
+
+        Self {
+            day,
+            entries,
+            table,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    // Bring current module into scope
+    use super::*;
+
+    /// An empty data directory of the test's own
+    fn data_directory(test: &str) -> path::PathBuf {
+        let directory = env::temp_dir()
+            .join(format!("authentication_tui_audit_{}_{test}", process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    /// A revoke sessions audit entry, made the days before today
+    fn entry(target: &str, days_ago: i64) -> domain::AuditEntry {
+        let mut entry = domain::AuditEntry::new(
+            "admin@example.com",
+            domain::AuditAction::RevokeSessions,
+            target,
+        )
+        .after(serde_json::json!({ "revoked": 2 }))
+        .status(tonic::Code::Ok);
+        entry.timestamp -= chrono::TimeDelta::days(days_ago);
+        entry
+    }
+
+    #[test]
+    fn entries_are_appended_as_json_lines() {
+        //-- Setup and Fixtures (Arrange)
+        let directory = data_directory("append");
+        let first = entry("first@example.com", 0);
+        let second = entry("second@example.com", 0);
+
+        //-- Execute Function (Act)
+        append(&directory, &first).unwrap();
+        append(&directory, &second).unwrap();
+        let contents = fs::read_to_string(directory.join(AUDIT_LOG_FILE)).unwrap();
+
+        //-- Checks (Assertions)
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let parsed: domain::AuditEntry = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(parsed, second);
+        assert!(lines[0].contains(r#""action":"RevokeSessions""#));
+        assert!(lines[0].contains(r#""status":"Ok""#));
+        let _ = fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn a_day_skips_other_days_and_malformed_lines() {
+        //-- Setup and Fixtures (Arrange)
+        let directory = data_directory("load_day");
+        let today = entry("today@example.com", 0);
+        append(&directory, &entry("yesterday@example.com", 1)).unwrap();
+        append(&directory, &today).unwrap();
+        let mut file = fs::OpenOptions::new()
+            .append(true)
+            .open(directory.join(AUDIT_LOG_FILE))
+            .unwrap();
+        writeln!(file, "{{\"not\": \"an entry\"").unwrap();
+        let day = chrono::Local::now().date_naive();
+
+        //-- Execute Function (Act)
+        let entries = load_day(&directory, day).unwrap();
+
+        //-- Checks (Assertions)
+        assert_eq!(entries, vec![today]);
+        assert_eq!(load_day(&data_directory("missing"), day).unwrap(), []);
+        let _ = fs::remove_dir_all(directory);
+    }
+}
@@ -0,0 +1,86 @@
+//-- ./src/state/auth.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Authentication state module, holding the forms shown when not logged in
+//! ---
+
+use crate::{
+    domain,
+    state::form::{FormField, FormState},
+};
+
+/// Email address field label
+pub const EMAIL: &str = "Email";
+
+/// Name field label
+pub const NAME: &str = "Name";
+
+/// Password field label
+pub const PASSWORD: &str = "Password";
+
+/// Password confirmation field label
+pub const CONFIRM_PASSWORD: &str = "Confirm password";
+
+/// Password reset token field label
+pub const RESET_TOKEN: &str = "Reset token";
+
+/// Authentication state model
+#[derive(Debug, Clone)]
+pub struct AuthState {
+    /// Login form
+    pub login: FormState,
+
+    /// Account registration form
+    pub register: FormState,
+
+    /// Password reset request form
+    pub forgot_password: FormState,
+
+    /// Password reset with token form
+    pub reset_password: FormState,
+}
+
+impl Default for AuthState {
+    fn default() -> Self {
+        let login = FormState::new(vec![
+            FormField::new(EMAIL),
+            FormField::new(PASSWORD).secret(),
+        ]);
+
+        let register = FormState::new(vec![
+            FormField::new(EMAIL),
+            FormField::new(NAME),
+            FormField::new(PASSWORD).secret(),
+            FormField::new(CONFIRM_PASSWORD).secret(),
+        ]);
+
+        let forgot_password = FormState::new(vec![FormField::new(EMAIL)]);
+
+        let reset_password = FormState::new(vec![
+            FormField::new(RESET_TOKEN).secret(),
+            FormField::new(PASSWORD).secret(),
+            FormField::new(CONFIRM_PASSWORD).secret(),
+        ]);
+
+        Self {
+            login,
+            register,
+            forgot_password,
+            reset_password,
+        }
+    }
+}
+
+impl AuthState {
+    /// The form shown on an authentication screen
+    pub fn form_mut(&mut self, screen: domain::Screen) -> Option<&mut FormState> {
+        match screen {
+            domain::Screen::Login => Some(&mut self.login),
+            domain::Screen::Register => Some(&mut self.register),
+            domain::Screen::ForgotPassword => Some(&mut self.forgot_password),
+            domain::Screen::ResetPassword => Some(&mut self.reset_password),
+            _ => None,
+        }
+    }
+}
@@ -5,7 +5,7 @@
 
 use std::time;
 
-use crate::domain;
+use crate::{domain, state};
 
 /// Backend state model
 #[derive(Debug, Clone, PartialEq)]
@@ -16,17 +16,38 @@ pub struct BackendState {
     /// When was the access token received
     pub access_token_time: Option<time::Instant>,
 
+    /// When the access token expires, from its claims
+    pub access_token_expires_at: Option<chrono::DateTime<chrono::Utc>>,
+
     /// Refresh token (session) return by the backend
     pub refresh_token: Option<String>,
 
     /// When was the refresh token received
     pub refresh_token_time: Option<time::Instant>,
 
+    /// Email address of the logged in operator
+    pub operator_email: Option<String>,
+
+    /// Role of the logged in operator, used to decide what is available
+    pub role: domain::UserRole,
+
     /// Is the backend online
     pub status: domain::BackendStatus,
 
+    /// When the backend status last changed
+    pub status_since: chrono::DateTime<chrono::Local>,
+
+    /// The error behind the last status change to a failing status
+    pub last_error: Option<String>,
+
+    /// When the last error happened
+    pub last_error_at: Option<chrono::DateTime<chrono::Local>>,
+
     /// When was the backend last checked for being online
     pub status_checked_on: Option<time::Instant>,
+
+    /// The round trip times of the latest pings
+    pub latency: state::LatencySamples,
 }
 
 impl Default for BackendState {
@@ -34,18 +55,32 @@ impl Default for BackendState {
     fn default() -> Self {
         let access_token = None;
         let access_token_time = None;
+        let access_token_expires_at = None;
         let refresh_token = None;
         let refresh_token_time = None;
-        let status = domain::BackendStatus::Offline;
+        let operator_email = None;
+        let role = domain::UserRole::default();
+        let status = domain::BackendStatus::default();
+        let status_since = chrono::Local::now();
+        let last_error = None;
+        let last_error_at = None;
         let status_checked_on = None;
+        let latency = state::LatencySamples::default();
 
         Self { 
             access_token, 
             access_token_time, 
+            access_token_expires_at,
             refresh_token, 
             refresh_token_time, 
+            operator_email,
+            role,
             status, 
+            status_since,
+            last_error,
+            last_error_at,
             status_checked_on, 
+            latency,
         }
     }
 }
@@ -55,6 +90,51 @@ impl BackendState {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Store the tokens returned by the backend, taking the operator role and
+    /// token expiry from the access token claims
+    pub fn set_tokens(&mut self, access_token: String, refresh_token: String) {
+        let now = time::Instant::now();
+
+        let claims = match domain::TokenClaims::decode(&access_token) {
+            Ok(claims) => claims,
+            Err(error) => {
+                tracing::error!("Error decoding access token claims: {}", error);
+                domain::TokenClaims::default()
+            }
+        };
+        self.role = claims
+            .role
+            .map(|role| domain::UserRole::parse(&role))
+            .unwrap_or_default();
+        self.access_token_expires_at = claims
+            .exp
+            .and_then(|exp| chrono::DateTime::from_timestamp(exp, 0));
+
+        self.access_token = Some(access_token);
+        self.access_token_time = Some(now);
+        self.refresh_token = Some(refresh_token);
+        self.refresh_token_time = Some(now);
+    }
+
+    /// Has the access token passed the expiry in its claims
+    pub fn is_token_expired(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        self.access_token.is_some()
+            && self
+                .access_token_expires_at
+                .is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// Forget the tokens and operator, returning to a guest role
+    pub fn clear_tokens(&mut self) {
+        self.access_token = None;
+        self.access_token_time = None;
+        self.access_token_expires_at = None;
+        self.refresh_token = None;
+        self.refresh_token_time = None;
+        self.operator_email = None;
+        self.role = domain::UserRole::default();
+    }
 }
 
 // #[cfg(test)]
@@ -0,0 +1,172 @@
+//-- ./src/state/form.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Form state module, a list of text input fields with one in focus
+//! ---
+
+use crossterm::event as crossterm;
+use tui_input::{Input, InputRequest};
+
+/// A labelled text input field
+#[derive(Debug, Clone)]
+pub struct FormField {
+    /// Label shown with the field, also used to look the field up
+    pub label: &'static str,
+
+    /// The text input
+    pub input: Input,
+
+    /// Mask the value when rendered, i.e. passwords
+    pub is_secret: bool,
+
+    /// Inline validation error for the field
+    pub error: Option<String>,
+}
+
+impl FormField {
+    /// Create a new empty form field
+    pub fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            input: Input::default(),
+            is_secret: false,
+            error: None,
+        }
+    }
+
+    /// Mask the field value when rendered
+    pub fn secret(mut self) -> Self {
+        self.is_secret = true;
+        self
+    }
+}
+
+/// Form state model
+#[derive(Debug, Clone)]
+pub struct FormState {
+    /// The form fields, in focus order
+    pub fields: Vec<FormField>,
+
+    /// Index of the field in focus
+    pub focus: usize,
+
+    /// Message shown with the form, i.e. the result of submitting it
+    pub notice: Option<String>,
+}
+
+impl FormState {
+    /// Construct a new form state with the first field in focus
+    pub fn new(fields: Vec<FormField>) -> Self {
+        Self {
+            fields,
+            focus: 0,
+            notice: None,
+        }
+    }
+
+    /// Get the value of the field with the given label
+    pub fn value(&self, label: &str) -> String {
+        self.fields
+            .iter()
+            .find(|field| field.label == label)
+            .map(|field| field.input.value().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Set the value of the field with the given label
+    pub fn set_value(&mut self, label: &str, value: impl Into<String>) {
+        if let Some(field) = self.fields.iter_mut().find(|f| f.label == label) {
+            field.input = Input::new(value.into());
+        }
+    }
+
+    /// Set an inline validation error on the field with the given label
+    pub fn set_error(&mut self, label: &str, error: impl Into<String>) {
+        if let Some(field) = self.fields.iter_mut().find(|f| f.label == label) {
+            field.error = Some(error.into());
+        }
+    }
+
+    /// Clear all inline validation errors
+    pub fn clear_errors(&mut self) {
+        for field in self.fields.iter_mut() {
+            field.error = None;
+        }
+    }
+
+    /// Does any field have a validation error
+    pub fn has_errors(&self) -> bool {
+        self.fields.iter().any(|field| field.error.is_some())
+    }
+
+    /// Clear the values of the secret fields
+    pub fn clear_secrets(&mut self) {
+        for field in self.fields.iter_mut().filter(|field| field.is_secret) {
+            field.input.reset();
+        }
+    }
+
+    /// Move focus to the next field, wrapping to the first
+    pub fn focus_next(&mut self) {
+        if !self.fields.is_empty() {
+            self.focus = (self.focus + 1) % self.fields.len();
+        }
+    }
+
+    /// Move focus to the previous field, wrapping to the last
+    pub fn focus_previous(&mut self) {
+        if !self.fields.is_empty() {
+            self.focus = (self.focus + self.fields.len() - 1) % self.fields.len();
+        }
+    }
+
+    /// Apply a key event to the field in focus
+    pub fn handle_key(&mut self, key_event: crossterm::KeyEvent) {
+        let is_ctrl = key_event
+            .modifiers
+            .contains(crossterm::KeyModifiers::CONTROL);
+
+        let request = match key_event.code {
+            crossterm::KeyCode::Char('u') if is_ctrl => InputRequest::DeleteLine,
+            crossterm::KeyCode::Char('w') if is_ctrl => InputRequest::DeletePrevWord,
+            crossterm::KeyCode::Char(c) => InputRequest::InsertChar(c),
+            crossterm::KeyCode::Backspace => InputRequest::DeletePrevChar,
+            crossterm::KeyCode::Delete => InputRequest::DeleteNextChar,
+            crossterm::KeyCode::Left => InputRequest::GoToPrevChar,
+            crossterm::KeyCode::Right => InputRequest::GoToNextChar,
+            crossterm::KeyCode::Home => InputRequest::GoToStart,
+            crossterm::KeyCode::End => InputRequest::GoToEnd,
+            _ => return,
+        };
+
+        if let Some(field) = self.fields.get_mut(self.focus) {
+            field.input.handle(request);
+            field.error = None;
+        }
+    }
+
+    /// The field in focus
+    pub fn focused(&self) -> Option<&FormField> {
+        self.fields.get(self.focus)
+    }
+
+    /// Replace the value of the field in focus, dropping line breaks
+    pub fn replace_focused(&mut self, text: &str) {
+        if let Some(field) = self.fields.get_mut(self.focus) {
+            let value: String = text.chars().filter(|c| !c.is_control()).collect();
+            field.input = Input::new(value);
+            field.error = None;
+        }
+    }
+
+    /// Insert pasted text into the field in focus, dropping line breaks
+    pub fn paste(&mut self, text: &str) {
+        if let Some(field) = self.fields.get_mut(self.focus) {
+            for c in text.chars().filter(|c| !c.is_control()) {
+                field.input.handle(InputRequest::InsertChar(c));
+            }
+            field.error = None;
+        }
+    }
+}
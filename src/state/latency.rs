@@ -0,0 +1,108 @@
+//-- ./src/state/latency.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Latency state module, a ring buffer of the backend ping round trip times
+//! with the statistics shown in the backend health panel
+//! ---
+
+use std::{collections::VecDeque, time};
+
+/// The most ping round trip times kept
+const DEFAULT_CAPACITY: usize = 60;
+
+/// Ring buffer of ping round trip times, oldest first
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatencySamples {
+    samples: VecDeque<time::Duration>,
+    capacity: usize,
+}
+
+impl Default for LatencySamples {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl LatencySamples {
+    /// Keep up to the capacity of samples, dropping the oldest once full
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Add a round trip time, dropping the oldest if full
+    pub fn push(&mut self, sample: time::Duration) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// The latest round trip time
+    pub fn last(&self) -> Option<time::Duration> {
+        self.samples.back().copied()
+    }
+
+    /// The fastest round trip time kept
+    pub fn min(&self) -> Option<time::Duration> {
+        self.samples.iter().min().copied()
+    }
+
+    /// The mean round trip time kept
+    pub fn average(&self) -> Option<time::Duration> {
+        let total: time::Duration = self.samples.iter().sum();
+
+        (!self.samples.is_empty()).then(|| total / self.samples.len() as u32)
+    }
+
+    /// The round trip time 95% of the samples kept are at or under
+    pub fn p95(&self) -> Option<time::Duration> {
+        let mut sorted: Vec<time::Duration> = self.samples.iter().copied().collect();
+        sorted.sort();
+
+        let rank = (sorted.len() * 95).div_ceil(100);
+        sorted.get(rank.checked_sub(1)?).copied()
+    }
+
+    /// The round trip times in milliseconds, oldest first, for a sparkline
+    pub fn millis(&self) -> Vec<u64> {
+        self.samples
+            .iter()
+            .map(|sample| sample.as_millis() as u64)
+            .collect()
+    }
+
+    /// Is the latest round trip time over the threshold
+    pub fn is_degraded(&self, threshold: time::Duration) -> bool {
+        self.last().is_some_and(|last| last > threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn samples_wrap_and_give_statistics() {
+        //-- Setup and Fixtures (Arrange)
+        let mut latency = LatencySamples::new(20);
+        let threshold = time::Duration::from_millis(100);
+
+        //-- Execute Function (Act)
+        for millis in 1..=25 {
+            latency.push(time::Duration::from_millis(millis * 10));
+        }
+
+        //-- Checks (Assertions)
+        assert_eq!(latency.millis().len(), 20);
+        assert_eq!(latency.min(), Some(time::Duration::from_millis(60)));
+        assert_eq!(latency.average(), Some(time::Duration::from_millis(155)));
+        assert_eq!(latency.p95(), Some(time::Duration::from_millis(240)));
+        assert!(latency.is_degraded(threshold));
+        assert_eq!(LatencySamples::default().p95(), None);
+    }
+}
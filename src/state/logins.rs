@@ -0,0 +1,79 @@
+//-- ./src/state/logins.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Logins state module, the most recent logins fetched by the logins watcher
+//! for the logins list
+//! ---
+
+use ratatui::widgets;
+
+use crate::domain;
+
+/// Logins list state model
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LoginsState {
+    /// The most recent logins, newest first
+    pub logins: Vec<domain::Login>,
+
+    /// The logins table selection and scroll offset
+    pub table: widgets::TableState,
+}
+
+impl LoginsState {
+    /// Replace the logins with a newer fetch, keeping the same login selected
+    /// while it is still listed, as new logins push it down the list
+    pub fn replace(&mut self, logins: Vec<domain::Login>) {
+        let selected_id = self
+            .table
+            .selected()
+            .and_then(|row| self.logins.get(row))
+            .map(|login| login.id.clone());
+
+        self.logins = logins;
+
+        let row = selected_id
+            .and_then(|id| self.logins.iter().position(|login| login.id == id));
+        self.table.select(row);
+    }
+
+    /// The other listed logins by the user of a login
+    pub fn by_same_user<'a>(
+        &'a self,
+        login: &'a domain::Login,
+    ) -> impl Iterator<Item = &'a domain::Login> {
+        self.logins.iter().filter(move |other| {
+            other.user_id == login.user_id && other.id != login.id
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn the_selected_login_stays_selected_as_new_logins_arrive() {
+        //-- Setup and Fixtures (Arrange)
+        let login = |id: &str| domain::Login {
+            id: id.to_string(),
+            user_id: "user".to_string(),
+            login_on: "2024-10-01T00:00:00Z".to_string(),
+            login_ip: None,
+        };
+        let mut logins = LoginsState::default();
+        logins.replace(vec![login("2"), login("1")]);
+        logins.table.select(Some(0));
+
+        //-- Execute Function (Act)
+        logins.replace(vec![login("3"), login("2"), login("1")]);
+        let kept = logins.table.selected();
+        logins.replace(vec![login("4"), login("3")]);
+
+        //-- Checks (Assertions)
+        assert_eq!(kept, Some(1));
+        assert_eq!(logins.table.selected(), None);
+        assert_eq!(logins.by_same_user(&logins.logins[0]).count(), 1);
+    }
+}
@@ -3,20 +3,69 @@
 //! Module for storing the application temporary state
 //! ---
 
+use ratatui::widgets;
+
+use crate::domain;
+
 /// App state module
 mod app;
+/// Audit log file and viewer state module
+pub mod audit;
+/// Authentication forms state module
+pub mod auth;
 mod backend;
+/// Text input form state module
+pub mod form;
+/// Backend ping round trip times module
+mod latency;
+pub use latency::LatencySamples;
+/// Most recent logins list state module
+mod logins;
+/// Clickable areas of the rendered frame module
+mod mouse;
+pub use mouse::{MouseState, MouseTarget};
+mod navigation;
+/// Notification history state module
+mod notifications;
+pub use notifications::{Notification, NotificationsState};
+mod security;
+pub use security::KnownIps;
+/// List and detail split pane state module
+mod split;
+pub use split::{SplitFocus, SplitState};
 mod toast;
+pub use toast::ToastState;
 
 pub struct State {
     /// The TUI application state
     pub app: app::AppState,
 
-    /// Toast message vector que
-    pub toast: toast::ToastState,
-
     /// The authentication backend server state
     pub backend: backend::BackendState,
+
+    /// Known login IP addresses and raised security events
+    pub security: security::SecurityState,
+
+    /// Audit log viewer state
+    pub audit: audit::AuditState,
+
+    /// The most recent logins, for the logins list
+    pub logins: logins::LoginsState,
+
+    /// Authentication forms, shown when not logged in
+    pub auth: auth::AuthState,
+
+    /// The screen being shown and the navigation history
+    pub navigation: navigation::NavigationState,
+
+    /// Clickable areas of the last rendered frame
+    pub mouse: MouseState,
+
+    /// The last table search, repeated with `n` and `N`
+    pub search: Option<String>,
+
+    /// How the list and the detail of its selected row share the screen
+    pub split: SplitState,
 }
 
 impl Default for State {
@@ -24,8 +73,143 @@ impl Default for State {
     fn default() -> Self {
         let app = app::AppState::default();
         let backend = backend::BackendState::default();
-        let toast = toast::ToastState::default();
+        let security = security::SecurityState::default();
+        let audit = audit::AuditState::default();
+        let logins = logins::LoginsState::default();
+        let auth = auth::AuthState::default();
+        let navigation = navigation::NavigationState::default();
+        let mouse = MouseState::default();
+        let search = None;
+        let split = SplitState::default();
+
+        Self {
+            app,
+            backend,
+            security,
+            audit,
+            logins,
+            auth,
+            navigation,
+            mouse,
+            search,
+            split,
+        }
+    }
+}
+
+impl State {
+    /// Forget the tokens and operator and show the login screen, with no
+    /// history to go back to the logged in screens through
+    pub fn log_out(&mut self) {
+        self.backend.clear_tokens();
+        self.navigation.reset(domain::Screen::Login);
+    }
+
+    /// The form being shown, that input mode keys and pastes go to
+    pub fn active_form_mut(&mut self) -> Option<&mut form::FormState> {
+        self.auth.form_mut(self.navigation.current)
+    }
+
+    /// Do list keys go to the detail pane, which only screens with one have
+    pub fn is_detail_focused(&self) -> bool {
+        self.navigation.current.has_detail() && self.split.is_detail_focused()
+    }
+
+    /// The text of each row of the table being shown, for searching
+    pub fn active_rows_text(&self) -> Vec<String> {
+        match self.navigation.current {
+            domain::Screen::Logins => self
+                .logins
+                .logins
+                .iter()
+                .map(|login| {
+                    let ip = login.login_ip.map(|ip| ip.to_string());
+                    format!(
+                        "{} {} {}",
+                        login.user_id,
+                        ip.unwrap_or_default(),
+                        login.login_on
+                    )
+                })
+                .collect(),
+            domain::Screen::Security => self
+                .security
+                .events
+                .iter()
+                .map(|event| {
+                    format!("{} {} {}", event.user_id, event.login_ip, event.login_on)
+                })
+                .collect(),
+            domain::Screen::AuditLog => self
+                .audit
+                .entries
+                .iter()
+                .map(|entry| {
+                    format!(
+                        "{} {} {} {}",
+                        entry.operator_label(),
+                        entry.action,
+                        entry.target,
+                        entry.status
+                    )
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The table being shown, that list selection actions go to, with the
+    /// number of rows it has
+    pub fn active_table_mut(&mut self) -> Option<(&mut widgets::TableState, usize)> {
+        match self.navigation.current {
+            domain::Screen::Logins => {
+                Some((&mut self.logins.table, self.logins.logins.len()))
+            }
+            domain::Screen::Security => {
+                Some((&mut self.security.table, self.security.events.len()))
+            }
+            domain::Screen::AuditLog => {
+                Some((&mut self.audit.table, self.audit.entries.len()))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn an_expired_token_leads_back_to_login() {
+        //-- Setup and Fixtures (Arrange)
+        let payload = r#"{"sub":"user-id","rol":"Admin","exp":1700000000}"#;
+        let payload =
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(payload);
+        let mut state = State::default();
+        state
+            .backend
+            .set_tokens(format!("header.{payload}.signature"), "refresh".into());
+        state.backend.operator_email = Some("admin@example.com".to_string());
+        state.navigation.reset(domain::Screen::Home);
+        state.navigation.navigate(domain::Screen::Security);
+        let is_expired = state.backend.is_token_expired(chrono::Utc::now());
+
+        //-- Execute Function (Act)
+        state.log_out();
 
-        Self { app, backend, toast }
+        //-- Checks (Assertions)
+        let is_logged_in = state.backend.access_token.is_some();
+        assert!(is_expired);
+        assert!(!is_logged_in);
+        assert_eq!(state.navigation.current, domain::Screen::Login);
+        assert!(domain::Screen::Login.is_available(is_logged_in));
+        assert!(!state.navigation.go_back(|_| true));
+        assert_eq!(state.backend.operator_email, None);
+        assert_eq!(state.backend.role, domain::UserRole::default());
+        assert!(!state.backend.is_token_expired(chrono::Utc::now()));
     }
 }
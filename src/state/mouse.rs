@@ -0,0 +1,50 @@
+//-- ./src/state/mouse.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Mouse state module, the clickable areas of the last rendered frame
+//! ---
+
+use ratatui::layout;
+
+/// What a clickable area of the screen is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MouseTarget {
+    /// A tab in the tab bar, counting from zero
+    Tab(usize),
+
+    /// A row of the table on the screen being shown, counting from zero
+    TableRow(usize),
+
+    /// The detail pane beside the table, while the table has focus
+    SplitDetail,
+}
+
+/// Hit-test registry, cleared and filled each time a frame is rendered
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MouseState {
+    areas: Vec<(layout::Rect, MouseTarget)>,
+}
+
+impl MouseState {
+    /// Forget the areas of the previous frame
+    pub fn clear(&mut self) {
+        self.areas.clear();
+    }
+
+    /// Register a clickable area, areas registered later are on top
+    pub fn register(&mut self, area: layout::Rect, target: MouseTarget) {
+        self.areas.push((area, target));
+    }
+
+    /// The topmost target at the terminal cell, if any
+    pub fn target_at(&self, column: u16, row: u16) -> Option<MouseTarget> {
+        let position = layout::Position::new(column, row);
+
+        self.areas
+            .iter()
+            .rev()
+            .find(|(area, _)| area.contains(position))
+            .map(|(_, target)| *target)
+    }
+}
@@ -0,0 +1,149 @@
+//-- ./src/state/navigation.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Navigation state module, the screen being shown and the history of screens
+//! to go back and forward through
+//! ---
+
+use crate::domain;
+
+/// Maximum number of screens kept in the back history
+const MAX_HISTORY: usize = 50;
+
+/// Navigation state model
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NavigationState {
+    /// The screen being shown
+    pub current: domain::Screen,
+
+    /// Screens to go back to, most recent last
+    pub back: Vec<domain::Screen>,
+
+    /// Screens to go forward to after going back, most recent last
+    pub forward: Vec<domain::Screen>,
+}
+
+impl NavigationState {
+    /// Show a screen, adding the current screen to the back history
+    pub fn navigate(&mut self, screen: domain::Screen) {
+        if screen == self.current {
+            return;
+        }
+
+        self.back.push(self.current);
+        if self.back.len() > MAX_HISTORY {
+            self.back.remove(0);
+        }
+        self.forward.clear();
+        self.current = screen;
+    }
+
+    /// Go back to the previous available screen, dropping the screens passed
+    /// over, i.e. the login forms once logged in. Returns false if there is
+    /// none.
+    pub fn go_back(
+        &mut self,
+        is_available: impl Fn(domain::Screen) -> bool,
+    ) -> bool {
+        while let Some(screen) = self.back.pop() {
+            if is_available(screen) {
+                self.forward.push(self.current);
+                self.current = screen;
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Go forward to the available screen gone back from, dropping the screens
+    /// passed over. Returns false if there is none.
+    pub fn go_forward(
+        &mut self,
+        is_available: impl Fn(domain::Screen) -> bool,
+    ) -> bool {
+        while let Some(screen) = self.forward.pop() {
+            if is_available(screen) {
+                self.back.push(self.current);
+                self.current = screen;
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Show a screen with no history, i.e. after logging in
+    pub fn reset(&mut self, screen: domain::Screen) {
+        self.current = screen;
+        self.back.clear();
+        self.forward.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn back_and_forward_through_history() {
+        //-- Setup and Fixtures (Arrange)
+        let mut navigation = NavigationState::default();
+        navigation.reset(domain::Screen::Home);
+
+        //-- Execute Function (Act)
+        navigation.navigate(domain::Screen::Security);
+        navigation.navigate(domain::Screen::AuditLog);
+        let went_back = navigation.go_back(|_| true);
+        let went_forward = navigation.go_forward(|_| true);
+        let went_forward_again = navigation.go_forward(|_| true);
+
+        //-- Checks (Assertions)
+        assert!(went_back);
+        assert!(went_forward);
+        assert!(!went_forward_again);
+        assert_eq!(navigation.current, domain::Screen::AuditLog);
+        assert_eq!(
+            navigation.back,
+            vec![domain::Screen::Home, domain::Screen::Security]
+        );
+    }
+
+    #[test]
+    fn navigate_clears_forward_history() {
+        //-- Setup and Fixtures (Arrange)
+        let mut navigation = NavigationState::default();
+        navigation.navigate(domain::Screen::Register);
+        navigation.go_back(|_| true);
+
+        //-- Execute Function (Act)
+        navigation.navigate(domain::Screen::ForgotPassword);
+
+        //-- Checks (Assertions)
+        assert!(navigation.forward.is_empty());
+        assert_eq!(navigation.current, domain::Screen::ForgotPassword);
+    }
+
+    #[test]
+    fn history_skips_unavailable_screens() {
+        //-- Setup and Fixtures (Arrange)
+        let mut navigation = NavigationState::default();
+        navigation.navigate(domain::Screen::Register);
+        navigation.navigate(domain::Screen::About);
+        navigation.navigate(domain::Screen::Home);
+        let is_logged_in = |screen: domain::Screen| screen.is_available(true);
+
+        //-- Execute Function (Act)
+        let went_back = navigation.go_back(is_logged_in);
+        let went_back_again = navigation.go_back(is_logged_in);
+
+        //-- Checks (Assertions)
+        assert!(went_back);
+        assert_eq!(navigation.current, domain::Screen::About);
+        assert!(!went_back_again);
+        assert!(navigation.back.is_empty());
+        assert_eq!(navigation.forward, vec![domain::Screen::Home]);
+    }
+}
@@ -0,0 +1,127 @@
+//-- ./src/state/notifications.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Notification history state module
+//! ---
+
+use std::collections::VecDeque;
+
+use crate::domain;
+
+/// A toast message kept in the history after it is shown
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// When the toast was raised
+    pub at: chrono::DateTime<chrono::Local>,
+
+    /// The kind of toast message
+    pub kind: domain::ToastKind,
+
+    /// The toast message
+    pub message: String,
+
+    /// Has the operator seen the notification history since it was raised
+    pub is_read: bool,
+}
+
+/// Bounded history of every toast message raised, newest first
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NotificationsState {
+    /// Past toast messages, newest first
+    pub history: VecDeque<Notification>,
+
+    /// The most notifications kept, the oldest are dropped first
+    pub capacity: usize,
+}
+
+impl NotificationsState {
+    /// Construct an empty history keeping up to a number of notifications
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            history: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Add a toast message to the history, dropping the oldest once full
+    pub fn push(&mut self, toast: &domain::Toast, is_read: bool) {
+        let notification = Notification {
+            at: chrono::Local::now(),
+            kind: toast.kind.clone(),
+            message: toast.message.clone(),
+            is_read,
+        };
+
+        self.history.push_front(notification);
+        self.history.truncate(self.capacity);
+    }
+
+    /// How many errors and warnings the operator has not seen
+    pub fn unread(&self) -> usize {
+        self.history
+            .iter()
+            .filter(|notification| !notification.is_read)
+            .filter(|notification| {
+                matches!(
+                    notification.kind,
+                    domain::ToastKind::Error | domain::ToastKind::Warning
+                )
+            })
+            .count()
+    }
+
+    /// Mark every notification as seen
+    pub fn mark_read(&mut self) {
+        for notification in self.history.iter_mut() {
+            notification.is_read = true;
+        }
+    }
+
+    /// The notifications of a kind, or all of them, newest first
+    pub fn filtered(&self, kind: Option<&domain::ToastKind>) -> Vec<&Notification> {
+        self.history
+            .iter()
+            .filter(|notification| {
+                kind.is_none_or(|kind| notification.kind == *kind)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn history_is_bounded_and_counts_unread_errors_and_warnings() {
+        //-- Setup and Fixtures (Arrange)
+        let mut notifications = NotificationsState::new(3);
+        let error = domain::Toast::new("Backend server is offline")
+            .kind(domain::ToastKind::Error);
+        let warning = domain::Toast::new("Login from a new IP address")
+            .kind(domain::ToastKind::Warning);
+        let info =
+            domain::Toast::new("Login to view Home").kind(domain::ToastKind::Info);
+
+        //-- Execute Function (Act)
+        notifications.push(&error, false);
+        notifications.push(&info, false);
+        notifications.push(&warning, false);
+        notifications.push(&error, false);
+
+        //-- Checks (Assertions)
+        assert_eq!(notifications.history.len(), 3);
+        assert_eq!(notifications.unread(), 2);
+        assert_eq!(
+            notifications
+                .filtered(Some(&domain::ToastKind::Error))
+                .len(),
+            1
+        );
+
+        notifications.mark_read();
+        assert_eq!(notifications.unread(), 0);
+    }
+}
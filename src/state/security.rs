@@ -0,0 +1,200 @@
+//-- ./src/state/security.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Security state module, tracking the IP addresses seen per user and the
+//! security events raised by the logins watcher
+//! ---
+
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    fs, net, path, time,
+};
+
+use ratatui::widgets;
+
+use crate::{domain, prelude::*};
+
+/// The file, in the data directory, used to store known login IP addresses
+const KNOWN_IPS_FILE: &str = "known_ips.json";
+
+/// Maximum number of security events kept in the list
+const MAX_SECURITY_EVENTS: usize = 100;
+
+/// Record of the IP addresses seen for each user id
+#[derive(Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct KnownIps(BTreeMap<String, BTreeSet<net::IpAddr>>);
+
+impl KnownIps {
+    /// Load the known IP record from the data directory, returning an empty
+    /// record if the file does not exist yet.
+    pub fn load(data_directory: &path::Path) -> Result<Self> {
+        let file = data_directory.join(KNOWN_IPS_FILE);
+
+        if !file.exists() {
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(file)?;
+        let known_ips = serde_json::from_str(&contents)?;
+
+        Ok(known_ips)
+    }
+
+    /// Save the known IP record to the data directory
+    pub fn save(&self, data_directory: &path::Path) -> Result<()> {
+        fs::create_dir_all(data_directory)?;
+
+        let file = data_directory.join(KNOWN_IPS_FILE);
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(file, contents)?;
+
+        Ok(())
+    }
+
+    /// Record an IP address against a user, returning true if the user already
+    /// had known IP addresses and this one is new.
+    ///
+    /// The first IP address recorded for a user is treated as their baseline
+    /// and is not reported as new.
+    pub fn record(&mut self, user_id: &str, ip: net::IpAddr) -> bool {
+        let ips = self.0.entry(user_id.to_string()).or_default();
+        let has_baseline = !ips.is_empty();
+
+        ips.insert(ip) && has_baseline
+    }
+
+    /// The IP addresses seen for a user
+    pub fn of_user(&self, user_id: &str) -> impl Iterator<Item = &net::IpAddr> {
+        self.0.get(user_id).into_iter().flatten()
+    }
+
+    /// Are there no IP addresses recorded for any user
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Security state model
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SecurityState {
+    /// The IP addresses seen for each user
+    pub known_ips: KnownIps,
+
+    /// Security events raised, newest first
+    pub events: VecDeque<domain::SecurityEvent>,
+
+    /// When were the logins last checked for new IP addresses
+    pub logins_checked_on: Option<time::Instant>,
+
+    /// The security events table selection and scroll offset
+    pub table: widgets::TableState,
+}
+
+impl SecurityState {
+    /// Add a security event to the front of the list, dropping the oldest
+    /// event if the list is full.
+    pub fn push_event(&mut self, event: domain::SecurityEvent) {
+        self.events.push_front(event);
+        self.events.truncate(MAX_SECURITY_EVENTS);
+    }
+
+    /// Record the IP addresses of a batch of logins, returning a security event
+    /// for each IP address new to its user.
+    ///
+    /// While no IP addresses are known, i.e. on a fresh install, the batch only
+    /// seeds the baseline. Otherwise every historic IP address in the batch
+    /// would be reported as new.
+    pub fn record_logins(
+        &mut self,
+        logins: &[domain::Login],
+    ) -> Vec<domain::SecurityEvent> {
+        let is_seeding = self.known_ips.is_empty();
+
+        let mut events = Vec::new();
+        for login in logins {
+            let Some(login_ip) = login.login_ip else {
+                continue;
+            };
+
+            let is_new_ip = self.known_ips.record(&login.user_id, login_ip);
+            if is_new_ip && !is_seeding {
+                events.push(domain::SecurityEvent::new_login_ip(
+                    &login.user_id,
+                    login_ip,
+                    &login.login_on,
+                ));
+            }
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn first_ip_for_user_is_baseline() {
+        //-- Setup and Fixtures (Arrange)
+        let mut known_ips = KnownIps::default();
+        let ip = net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 0, 1));
+
+        //-- Execute Function (Act)
+        let is_new = known_ips.record("user", ip);
+
+        //-- Checks (Assertions)
+        assert!(!is_new);
+    }
+
+    #[test]
+    fn only_unseen_ips_are_new() {
+        //-- Setup and Fixtures (Arrange)
+        let mut known_ips = KnownIps::default();
+        let home = net::IpAddr::V4(net::Ipv4Addr::new(10, 0, 0, 1));
+        let away = net::IpAddr::V4(net::Ipv4Addr::new(192, 168, 1, 1));
+        known_ips.record("user", home);
+
+        //-- Execute Function (Act)
+        let home_again = known_ips.record("user", home);
+        let away_first = known_ips.record("user", away);
+        let away_again = known_ips.record("user", away);
+
+        //-- Checks (Assertions)
+        assert!(!home_again);
+        assert!(away_first);
+        assert!(!away_again);
+    }
+
+    #[test]
+    fn first_poll_seeds_the_baseline_without_events() {
+        //-- Setup and Fixtures (Arrange)
+        let mut security = SecurityState::default();
+        let login = |user_id: &str, ip: [u8; 4]| domain::Login {
+            id: format!("{user_id}-{ip:?}"),
+            user_id: user_id.to_string(),
+            login_on: "2024-10-01T00:00:00Z".to_string(),
+            login_ip: Some(net::IpAddr::from(ip)),
+        };
+        let history = vec![
+            login("ann", [10, 0, 0, 1]),
+            login("ann", [10, 0, 0, 2]),
+            login("bob", [10, 0, 0, 3]),
+        ];
+
+        //-- Execute Function (Act)
+        let seeded = security.record_logins(&history);
+        let next = security.record_logins(&[
+            login("ann", [10, 0, 0, 2]),
+            login("bob", [192, 168, 1, 1]),
+        ]);
+
+        //-- Checks (Assertions)
+        assert!(seeded.is_empty());
+        assert_eq!(next.len(), 1);
+        assert_eq!(next[0].user_id, "bob");
+        assert_eq!(next[0].login_ip, net::IpAddr::from([192, 168, 1, 1]));
+    }
+}
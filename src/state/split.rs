@@ -0,0 +1,106 @@
+//-- ./src/state/split.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Split pane state module, how the list and the detail of its selected row
+//! share the screen and which of them has focus
+//! ---
+
+/// The narrowest share of the screen either pane is resized to, as a percentage
+const MIN_PERCENT: u16 = 20;
+
+/// How much a pane grows or shrinks each resize, as a percentage
+const RESIZE_STEP: u16 = 5;
+
+/// The pane that list keys go to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplitFocus {
+    /// Keys move the row selection
+    #[default]
+    List,
+
+    /// Keys scroll the detail of the selected row
+    Detail,
+}
+
+/// Split pane state model
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitState {
+    /// The share of the screen taken by the list, as a percentage
+    pub list_percent: u16,
+
+    /// Swap the side by side and stacked panes the terminal width picks
+    pub is_flipped: bool,
+
+    /// The pane that list keys go to
+    pub focus: SplitFocus,
+
+    /// Lines the detail is scrolled down by
+    pub detail_scroll: u16,
+}
+
+impl Default for SplitState {
+    fn default() -> Self {
+        Self {
+            list_percent: 60,
+            is_flipped: false,
+            focus: SplitFocus::default(),
+            detail_scroll: 0,
+        }
+    }
+}
+
+impl SplitState {
+    /// Grow the list pane by a step, or shrink it if `steps` is negative
+    pub fn resize(&mut self, steps: i16) {
+        let step = steps.saturating_mul(RESIZE_STEP as i16);
+        let percent = (self.list_percent as i16).saturating_add(step);
+
+        self.list_percent =
+            percent.clamp(MIN_PERCENT as i16, (100 - MIN_PERCENT) as i16) as u16;
+    }
+
+    /// Move focus to the other pane
+    pub fn toggle_focus(&mut self) {
+        self.focus = match self.focus {
+            SplitFocus::List => SplitFocus::Detail,
+            SplitFocus::Detail => SplitFocus::List,
+        };
+    }
+
+    /// Does the detail pane have focus
+    pub fn is_detail_focused(&self) -> bool {
+        self.focus == SplitFocus::Detail
+    }
+
+    /// Scroll the detail down by a number of lines, or up if negative
+    pub fn scroll_detail(&mut self, lines: isize) {
+        let scroll = (self.detail_scroll as isize).saturating_add(lines);
+
+        self.detail_scroll = scroll.clamp(0, u16::MAX as isize) as u16;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn resizing_keeps_both_panes_visible() {
+        //-- Setup and Fixtures (Arrange)
+        let mut split = SplitState::default();
+
+        //-- Execute Function (Act)
+        split.resize(100);
+        let grown = split.list_percent;
+        split.resize(-100);
+        let shrunk = split.list_percent;
+        split.resize(1);
+
+        //-- Checks (Assertions)
+        assert_eq!(grown, 100 - MIN_PERCENT);
+        assert_eq!(shrunk, MIN_PERCENT);
+        assert_eq!(split.list_percent, MIN_PERCENT + RESIZE_STEP);
+    }
+}
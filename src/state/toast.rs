@@ -1,16 +1,14 @@
-use std::collections::VecDeque;
+use std::{collections::VecDeque, time};
 
 use crate::domain;
 
-
-
 #[derive(Debug, Clone, PartialEq)]
 pub struct ToastState {
     /// Toast queue to store messages as they are triggered
     pub queue: VecDeque<domain::Toast>,
 
-    /// The toast message being displayed. It can be none, so it is within an Option
-    pub current: Option<domain::Toast>,
+    /// The toast messages being displayed, newest first
+    pub visible: Vec<domain::Toast>,
 }
 
 impl Default for ToastState {
@@ -24,11 +22,79 @@ impl ToastState {
     /// Construct a new application state instance
     pub fn new() -> Self {
         let queue = VecDeque::new();
-        let current = None;
+        let visible = Vec::new();
 
         Self {
             queue,
-            current
+            visible
+        }
+    }
+
+    /// Add a toast to the queue, or count it against the same message if it
+    /// is already shown or queued
+    pub fn push(&mut self, toast: domain::Toast) {
+        let same_message = self
+            .visible
+            .iter_mut()
+            .chain(self.queue.iter_mut())
+            .find(|other| other.is_same_message(&toast));
+
+        match same_message {
+            Some(other) => {
+                other.count += 1;
+                other.shown_at = time::Instant::now();
+            }
+            None => self.queue.push_back(toast),
         }
     }
-}
\ No newline at end of file
+
+    /// Dismiss a visible toast, counting from the newest
+    pub fn dismiss(&mut self, index: usize) -> Option<domain::Toast> {
+        (index < self.visible.len()).then(|| self.visible.remove(index))
+    }
+
+    /// Remove the visible toasts shown for longer than their duration, then
+    /// show queued toasts up to the most visible at once
+    pub fn expire(
+        &mut self,
+        duration: impl Fn(&domain::ToastKind) -> Option<time::Duration>,
+        max_visible: usize,
+    ) {
+        self.visible.retain(|toast| match duration(&toast.kind) {
+            Some(duration) => toast.shown_at.elapsed() <= duration,
+            None => true,
+        });
+
+        while self.visible.len() < max_visible {
+            let Some(toast) = self.queue.pop_front() else {
+                break;
+            };
+            self.visible.insert(0, toast.shown_now());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn duplicate_toasts_are_merged() {
+        //-- Setup and Fixtures (Arrange)
+        let mut toasts = ToastState::new();
+        let offline = domain::Toast::new("Backend server is offline")
+            .kind(domain::ToastKind::Error);
+
+        //-- Execute Function (Act)
+        toasts.push(offline.clone());
+        toasts.expire(|_| None, 3);
+        toasts.push(offline.clone());
+        toasts.push(offline.kind(domain::ToastKind::Warning));
+        toasts.expire(|_| None, 3);
+
+        //-- Checks (Assertions)
+        assert_eq!(toasts.visible.len(), 2);
+        assert_eq!(toasts.visible[1].count, 2);
+    }
+}
@@ -12,6 +12,8 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
+use base64::Engine;
+
 use crate::{handlers, prelude::*};
 
 #[derive(Debug)]
@@ -82,6 +84,9 @@ impl Terminal {
             crossterm::cursor::Hide
         )?;
 
+        // Report focus changes, to draw less often while out of focus
+        crossterm::execute!(io::stdout(), crossterm::event::EnableFocusChange)?;
+
         // Enable mouse event capture
         if self.mouse_enabled {
             crossterm::execute!(io::stdout(), crossterm::event::EnableMouseCapture)?;
@@ -112,6 +117,8 @@ impl Terminal {
             // Flush the terminal backend
             self.backend.flush()?;
 
+            crossterm::execute!(io::stdout(), crossterm::event::DisableFocusChange)?;
+
             // Disable terminal backend mouse event capture
             if self.mouse_enabled {
                 crossterm::execute!(
@@ -141,6 +148,46 @@ impl Terminal {
 
         Ok(())
     }
+
+    /// Hand the terminal back to the shell while running a function, i.e. an
+    /// editor, then take it back again. The caller should redraw the whole
+    /// screen afterwards.
+    pub fn hand_over<T>(&mut self, function: impl FnOnce() -> T) -> Result<T> {
+        // Stop reading terminal events, so the shell or editor gets the keys
+        self.events.cancel();
+        self.restore()?;
+
+        let result = function();
+
+        self.enter()?;
+
+        Ok(result)
+    }
+
+    /// Suspend the process to the shell, as Ctrl-Z does outside raw mode. The
+    /// process carries on once the shell sends SIGCONT, i.e. with `fg`.
+    pub fn suspend(&mut self) -> Result<()> {
+        self.hand_over(|| {
+            // SAFETY: raise only sends a signal to this process, stopping it
+            // until it is continued
+            #[cfg(unix)]
+            unsafe {
+                libc::raise(libc::SIGTSTP);
+            }
+        })
+    }
+
+    /// Copy text to the clipboard of the terminal emulator, using the OSC 52
+    /// escape sequence so it also works over SSH
+    pub fn copy_to_clipboard(&mut self, text: &str) -> Result<()> {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+        crossterm::execute!(
+            io::stdout(),
+            crossterm::style::Print(format!("\x1b]52;c;{encoded}\x07"))
+        )?;
+
+        Ok(())
+    }
 }
 
 impl Deref for Terminal {
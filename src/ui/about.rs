@@ -0,0 +1,50 @@
+//-- ./src/ui/about.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # About screen ui layout
+//!
+//! Contains the layout code for rendering the TUI build information and the
+//! backend versions the TUI supports
+//! ---
+
+use ratatui::{layout, text, widgets};
+
+use crate::{cli, ui};
+
+pub fn render(
+    theme: &ui::Theme,
+    area: layout::Rect,
+    frame: &mut ratatui::Frame,
+) {
+    let supported = cli::SUPPORTED_BACKEND;
+
+    let heading = |title| text::Line::styled(title, theme.style("tab.selected"));
+    let mut lines = vec![
+        heading("TUI"),
+        ui::split::field("Version", cli::VERSION_MESSAGE.to_string(), theme),
+        ui::split::field("Commit", cli::GIT_SHA.to_string(), theme),
+        ui::split::field("Target", cli::TARGET_TRIPLE.to_string(), theme),
+        text::Line::default(),
+        heading("Backend"),
+        ui::split::field(
+            "Supports",
+            format!("{} up to {}", supported.start, supported.end),
+            theme,
+        ),
+    ];
+
+    // The backend does not report its version yet, so it can not be checked
+    let mut version_line = ui::split::field("Version", String::new(), theme);
+    version_line.push_span(text::Span::styled(
+        "Not reported, compatibility is not checked",
+        theme.style("text.dimmed"),
+    ));
+    lines.push(version_line);
+
+    let paragraph = widgets::Paragraph::new(lines)
+        .block(widgets::Block::bordered().title(" About "))
+        .wrap(widgets::Wrap { trim: false });
+
+    frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,111 @@
+//-- ./src/ui/audit.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Audit log viewer ui layout
+//!
+//! Contains the layout code for rendering the audit log entries of a day
+//! ---
+
+use ratatui::{layout, style, text, widgets};
+
+use crate::{domain, state, ui};
+
+pub fn render(
+    state: &mut state::State,
+    theme: &ui::Theme,
+    area: layout::Rect,
+    frame: &mut ratatui::Frame,
+) {
+    // Title the viewer with the day shown and how to change it
+    let title = format!(
+        " Audit log: {} ({} entries) ",
+        state.audit.day.format("%a %d %b %Y"),
+        state.audit.entries.len()
+    );
+    let block = widgets::Block::bordered().title(title).title_bottom(
+        text::Line::from(" < previous day | next day > ").right_aligned(),
+    );
+
+    // Drop the operator, status and after columns on narrow terminals
+    let breakpoint = ui::Breakpoint::of(area);
+    let all_columns = [0, 1, 2, 3, 4, 5];
+    let columns: &[usize] = breakpoint.pick(&[0, 2, 3], &all_columns, &all_columns);
+
+    // Construct the header row
+    let header = widgets::Row::new(ui::helpers::pick_columns(
+        ["Time", "Operator", "Action", "Target", "Status", "After"],
+        columns,
+    ))
+    .style(theme.style("table.header"));
+
+    // Construct a row for each audit entry
+    let rows = state
+        .audit
+        .entries
+        .iter()
+        .map(|entry| audit_row(entry, columns, theme));
+
+    let target_width = breakpoint.pick(24, 24, 36);
+    let widths = ui::helpers::pick_columns(
+        [
+            layout::Constraint::Length(8),
+            layout::Constraint::Length(24),
+            layout::Constraint::Length(14),
+            layout::Constraint::Length(target_width),
+            layout::Constraint::Length(16),
+            layout::Constraint::Fill(1),
+        ],
+        columns,
+    );
+
+    let table = widgets::Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .highlight_style(theme.style("table.selected"));
+
+    frame.render_stateful_widget(table, area, &mut state.audit.table);
+
+    // Make the visible rows clickable
+    let len = state.audit.entries.len();
+    ui::helpers::register_table_rows(&mut state.mouse, area, &state.audit.table, len);
+}
+
+/// Build the table row for an audit entry
+fn audit_row(
+    entry: &domain::AuditEntry,
+    columns: &[usize],
+    theme: &ui::Theme,
+) -> widgets::Row<'static> {
+    let time = entry
+        .timestamp
+        .with_timezone(&chrono::Local)
+        .format("%H:%M:%S")
+        .to_string();
+
+    // Dim the row if the backend did not accept the action
+    let style = if entry.status == format!("{:?}", tonic::Code::Ok) {
+        style::Style::default()
+    } else {
+        theme.style("table.dimmed")
+    };
+
+    let cells = [
+        time,
+        entry.operator_label().to_string(),
+        entry.action.to_string(),
+        entry.target.clone(),
+        entry.status.clone(),
+        after(entry),
+    ];
+
+    widgets::Row::new(ui::helpers::pick_columns(cells, columns)).style(style)
+}
+
+/// The value after the action, on one line
+fn after(entry: &domain::AuditEntry) -> String {
+    match &entry.after {
+        Some(value) => value.to_string(),
+        None => "-".to_string(),
+    }
+}
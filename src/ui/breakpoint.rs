@@ -0,0 +1,93 @@
+//-- ./src/ui/breakpoint.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Layout breakpoints
+//!
+//! Layouts adapt to the terminal width, picking sizes and columns for narrow,
+//! normal and wide terminals. Below the minimum size a "terminal too small"
+//! screen is shown instead of the layout.
+//! ---
+
+use ratatui::layout;
+
+/// The narrowest terminal the layout is rendered in
+pub const MIN_WIDTH: u16 = 40;
+
+/// The shortest terminal the layout is rendered in
+pub const MIN_HEIGHT: u16 = 10;
+
+/// Terminals narrower than this are narrow
+const NORMAL_WIDTH: u16 = 80;
+
+/// Terminals at least this wide are wide
+const WIDE_WIDTH: u16 = 140;
+
+/// Terminal width breakpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Narrower than 80 columns, drop columns and widen overlays
+    Narrow,
+
+    /// The layout as designed
+    Normal,
+
+    /// At least 140 columns, give tables and toasts more room
+    Wide,
+}
+
+impl Breakpoint {
+    /// The breakpoint for the width of the area
+    pub fn of(area: layout::Rect) -> Self {
+        match area.width {
+            width if width < NORMAL_WIDTH => Breakpoint::Narrow,
+            width if width < WIDE_WIDTH => Breakpoint::Normal,
+            _ => Breakpoint::Wide,
+        }
+    }
+
+    /// Pick the value for the breakpoint
+    pub fn pick<T>(self, narrow: T, normal: T, wide: T) -> T {
+        match self {
+            Breakpoint::Narrow => narrow,
+            Breakpoint::Normal => normal,
+            Breakpoint::Wide => wide,
+        }
+    }
+
+    /// Is the narrow breakpoint
+    pub fn is_narrow(self) -> bool {
+        self == Breakpoint::Narrow
+    }
+}
+
+/// Is the area too small to render the layout in
+pub fn is_too_small(area: layout::Rect) -> bool {
+    area.width < MIN_WIDTH || area.height < MIN_HEIGHT
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn breakpoints_follow_the_terminal_width() {
+        //-- Setup and Fixtures (Arrange)
+        let narrow = layout::Rect::new(0, 0, 79, 24);
+        let normal = layout::Rect::new(0, 0, 80, 24);
+        let wide = layout::Rect::new(0, 0, 140, 24);
+        let tiny = layout::Rect::new(0, 0, 39, 5);
+
+        //-- Execute Function (Act)
+        let breakpoints = [narrow, normal, wide].map(Breakpoint::of);
+
+        //-- Checks (Assertions)
+        assert_eq!(
+            breakpoints,
+            [Breakpoint::Narrow, Breakpoint::Normal, Breakpoint::Wide]
+        );
+        assert!(is_too_small(tiny));
+        assert!(!is_too_small(narrow));
+    }
+}
@@ -0,0 +1,354 @@
+//-- ./src/ui/components/footer.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Footer component
+//!
+//! Renders the application mode and backend status along the bottom of the
+//! terminal, with segments between them for a request spinner, the count and
+//! keys pending, the backend endpoint, the operator email, the last ping
+//! latency, the time until the access token expires and the unread
+//! notification count. Segments are dropped, lowest priority first, when the
+//! terminal is too narrow to show them all.
+//! ---
+
+use std::time;
+
+use crossterm::event as crossterm;
+use ratatui::{layout, style, text, widgets};
+
+use crate::{domain, prelude::*, ui};
+
+/// The width of the backend status, which always fits
+const STATUS_WIDTH: u16 = 12;
+
+/// Frames of the spinner shown while a request is in flight
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Warn the token is about to expire once it has less than this left
+const TOKEN_WARNING: chrono::TimeDelta = chrono::TimeDelta::minutes(5);
+
+/// A footer segment between the application mode and the backend status
+#[derive(Debug, Clone, PartialEq)]
+struct Segment {
+    /// Text shown, padded with a space each side
+    label: String,
+
+    /// Style to render the label with
+    style: style::Style,
+
+    /// Segments with a lower priority are dropped first
+    priority: u8,
+
+    /// The action sent when the segment is clicked, if any
+    on_click: Option<domain::Action>,
+}
+
+impl Segment {
+    /// A segment showing the text
+    fn new(text: impl AsRef<str>, style: style::Style, priority: u8) -> Self {
+        Self {
+            label: format!(" {} ", text.as_ref()),
+            style,
+            priority,
+            on_click: None,
+        }
+    }
+
+    /// Send the action when the segment is clicked
+    fn on_click(mut self, action: domain::Action) -> Self {
+        self.on_click = Some(action);
+        self
+    }
+
+    /// The width the segment takes in the footer
+    fn width(&self) -> u16 {
+        text::Span::raw(self.label.as_str()).width() as u16
+    }
+}
+
+/// Footer component, tracking the state it shows from actions
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FooterComponent {
+    /// Named styles to render with
+    theme: ui::Theme,
+
+    /// The backend server address requests are made to
+    endpoint: String,
+
+    /// Pings slower than this are shown as degraded
+    degraded_latency: time::Duration,
+
+    /// The application mode
+    app_mode: domain::AppMode,
+
+    /// The authentication backend server status
+    backend_status: domain::BackendStatus,
+
+    /// Is a request to the backend in flight
+    is_busy: bool,
+
+    /// The spinner frame shown next while busy
+    spinner_frame: usize,
+
+    /// The count and keys of an incomplete key sequence, i.e. `5g`
+    pending: String,
+
+    /// Email address of the logged in operator
+    operator_email: Option<String>,
+
+    /// How long the last backend ping took to be answered
+    latency: Option<time::Duration>,
+
+    /// When the access token expires
+    token_expires_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Unread error and warning notifications
+    unread: usize,
+
+    /// Where the application mode was last rendered, for mouse clicks
+    app_mode_area: layout::Rect,
+
+    /// Where the clickable segments were last rendered, with their actions
+    segment_areas: Vec<(layout::Rect, domain::Action)>,
+
+    /// Where the backend status was last rendered, for mouse clicks
+    status_area: layout::Rect,
+}
+
+impl FooterComponent {
+    /// Construct the footer, showing the backend endpoint requests go to
+    pub fn new(
+        theme: ui::Theme,
+        endpoint: String,
+        degraded_latency: time::Duration,
+    ) -> Self {
+        Self {
+            theme,
+            endpoint,
+            degraded_latency,
+            ..Self::default()
+        }
+    }
+
+    /// Build the segments to show, in the order they are shown
+    fn segments(&mut self, narrow: bool) -> Vec<Segment> {
+        let dimmed = self.theme.style("text.dimmed");
+        let mut segments = Vec::new();
+
+        if self.is_busy {
+            let frame = SPINNER[self.spinner_frame % SPINNER.len()];
+            self.spinner_frame += 1;
+            segments.push(Segment::new(frame, self.theme.style("toast.info"), 6));
+        }
+
+        if !self.pending.is_empty() {
+            let style = self.theme.style("mode.input");
+            segments.push(Segment::new(&self.pending, style, 5));
+        }
+
+        segments.push(Segment::new(&self.endpoint, dimmed, 0));
+
+        if let Some(email) = &self.operator_email {
+            segments.push(Segment::new(email, dimmed, 1));
+        }
+
+        if let Some(latency) = self.latency {
+            let label = format!("{}ms", latency.as_millis());
+            let style = if latency > self.degraded_latency {
+                self.theme.style("toast.warning")
+            } else {
+                dimmed
+            };
+            let segment = Segment::new(label, style, 2)
+                .on_click(domain::Action::BackendStatusUpdate);
+            segments.push(segment);
+        }
+
+        if let Some(expires_at) = self.token_expires_at {
+            let left = expires_at - chrono::Utc::now();
+            let style = if left <= chrono::TimeDelta::zero() {
+                self.theme.style("toast.error")
+            } else if left < TOKEN_WARNING {
+                self.theme.style("toast.warning")
+            } else {
+                dimmed
+            };
+            let label = format!("token {}", countdown(left));
+            segments.push(Segment::new(label, style, 3));
+        }
+
+        // Shorten the unread count on narrow terminals
+        if self.unread > 0 {
+            let label = if narrow {
+                format!("!{}", self.unread)
+            } else {
+                format!("! {} unread", self.unread)
+            };
+            let segment = Segment::new(label, self.theme.style("toast.warning"), 4)
+                .on_click(domain::Action::Notifications);
+            segments.push(segment);
+        }
+
+        segments
+    }
+}
+
+impl ui::components::Component for FooterComponent {
+    fn handle_mouse(
+        &mut self,
+        mouse_event: crossterm::MouseEvent,
+    ) -> Result<Option<domain::Action>> {
+        if mouse_event.kind != crossterm::MouseEventKind::Down(crossterm::MouseButton::Left) {
+            return Ok(None);
+        }
+        let position = layout::Position::new(mouse_event.column, mouse_event.row);
+
+        // Toggle the application mode
+        if self.app_mode_area.contains(position) {
+            let mode = match self.app_mode {
+                domain::AppMode::Normal => domain::AppMode::Input,
+                domain::AppMode::Input => domain::AppMode::Normal,
+            };
+            return Ok(Some(domain::Action::AppMode(mode)));
+        }
+
+        // Show the notification history, or check the latency again
+        if let Some((_, action)) = self
+            .segment_areas
+            .iter()
+            .find(|(area, _)| area.contains(position))
+        {
+            return Ok(Some(action.clone()));
+        }
+
+        // Check the backend status now
+        if self.status_area.contains(position) {
+            return Ok(Some(domain::Action::BackendStatusUpdate));
+        }
+
+        Ok(None)
+    }
+
+    fn update(&mut self, action: &domain::Action) -> Result<Option<domain::Action>> {
+        match action {
+            domain::Action::AppMode(mode) => self.app_mode = mode.clone(),
+            domain::Action::BackendStatus(status) => {
+                self.backend_status = status.clone()
+            }
+            domain::Action::BackendLatency(latency) => self.latency = Some(*latency),
+            domain::Action::Busy(is_busy) => self.is_busy = *is_busy,
+            domain::Action::PendingKeys(pending) => self.pending = pending.clone(),
+            domain::Action::Session(email, expires_at) => {
+                self.operator_email = email.clone();
+                self.token_expires_at = *expires_at;
+            }
+            domain::Action::NotificationsUnread(unread) => self.unread = *unread,
+            _ => {}
+        }
+
+        Ok(None)
+    }
+
+    fn render(&mut self, frame: &mut ratatui::Frame, area: layout::Rect) {
+        let breakpoint = ui::Breakpoint::of(area);
+
+        // Let the mode shrink on narrow terminals, the status always fits
+        let mode_width = breakpoint.pick(10, 24, 24);
+
+        // Keep the segments that fit between the mode and the status
+        let room = area.width.saturating_sub(mode_width + STATUS_WIDTH);
+        let segments = fit(self.segments(breakpoint.is_narrow()), room);
+
+        let mut constraints = vec![layout::Constraint::Min(mode_width)];
+        constraints.extend(
+            segments
+                .iter()
+                .map(|segment| layout::Constraint::Length(segment.width())),
+        );
+        constraints.push(layout::Constraint::Length(STATUS_WIDTH));
+        let split = layout::Layout::horizontal(constraints).split(area);
+
+        let footer_left = split[0];
+        let status_area = split[split.len() - 1];
+        self.app_mode_area = footer_left;
+        self.status_area = status_area;
+        self.segment_areas.clear();
+
+        let status_app_mode_widget =
+            ui::custom_widgets::StatusAppModeWidget::init(self.app_mode.clone(), &self.theme);
+        frame.render_widget(status_app_mode_widget, footer_left);
+
+        for (segment, segment_area) in segments.into_iter().zip(split[1..].iter()) {
+            if let Some(action) = segment.on_click {
+                self.segment_areas.push((*segment_area, action));
+            }
+            let span = text::Span::styled(segment.label, segment.style);
+            frame.render_widget(widgets::Paragraph::new(span), *segment_area);
+        }
+
+        let status_widget =
+            ui::custom_widgets::StatusWidget::init(self.backend_status.clone(), &self.theme);
+        frame.render_widget(status_widget, status_area);
+    }
+}
+
+/// Drop the lowest priority segments until the rest fit in the room, keeping
+/// the order they are shown in
+fn fit(mut segments: Vec<Segment>, room: u16) -> Vec<Segment> {
+    while segments.iter().map(Segment::width).sum::<u16>() > room {
+        let Some(lowest) = segments
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, segment)| segment.priority)
+            .map(|(index, _)| index)
+        else {
+            break;
+        };
+        segments.remove(lowest);
+    }
+
+    segments
+}
+
+/// Count down the time left, i.e. `14:05` or `2h05m`
+fn countdown(left: chrono::TimeDelta) -> String {
+    let seconds = left.num_seconds();
+
+    match seconds {
+        ..=0 => "expired".to_string(),
+        1..3600 => format!("{}:{:02}", seconds / 60, seconds % 60),
+        _ => format!("{}h{:02}m", seconds / 3600, seconds % 3600 / 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn narrow_footers_drop_the_lowest_priority_segments() {
+        //-- Setup and Fixtures (Arrange)
+        let segment = |text: &str, priority| {
+            Segment::new(text, style::Style::default(), priority)
+        };
+        let segments = vec![
+            segment("127.0.0.1:8081", 0),
+            segment("admin@example.com", 1),
+            segment("12ms", 2),
+            segment("! 3 unread", 4),
+        ];
+
+        //-- Execute Function (Act)
+        let fitted = fit(segments.clone(), 20);
+        let labels: Vec<&str> = fitted.iter().map(|s| s.label.as_str()).collect();
+
+        //-- Checks (Assertions)
+        assert_eq!(labels, [" 12ms ", " ! 3 unread "]);
+        assert_eq!(fit(segments, 0), []);
+        assert_eq!(countdown(chrono::TimeDelta::seconds(845)), "14:05");
+        assert_eq!(countdown(chrono::TimeDelta::seconds(7500)), "2h05m");
+        assert_eq!(countdown(chrono::TimeDelta::zero()), "expired");
+    }
+}
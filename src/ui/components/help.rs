@@ -0,0 +1,203 @@
+//-- ./src/ui/components/help.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Help overlay component
+//!
+//! Lists the key bindings of the live keymap for the application mode and
+//! screen the help was opened on, grouped by category. Bindings the operator
+//! role is not permitted to use are greyed out.
+//! ---
+
+use crossterm::event as crossterm;
+use ratatui::{layout, style, text, widgets};
+
+use crate::{domain, prelude::*, ui};
+
+/// The categories in the order they are listed
+const CATEGORIES: [&str; 6] = [
+    "Navigation",
+    "Lists",
+    "Forms",
+    "Audit log",
+    "Backend",
+    "Application",
+];
+
+/// Help overlay component
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HelpComponent {
+    /// The keymap the help is built from
+    keymap: domain::Keymap,
+
+    /// The help lines, built when the help is shown. Empty while closed.
+    lines: Vec<text::Line<'static>>,
+
+    /// The title, naming the mode and screen the help is for
+    title: String,
+
+    /// Lines scrolled past
+    scroll: u16,
+
+    /// Named styles to render with
+    theme: ui::Theme,
+}
+
+impl HelpComponent {
+    /// Construct the help overlay for the keymap
+    pub fn new(keymap: domain::Keymap, theme: ui::Theme) -> Self {
+        Self {
+            keymap,
+            theme,
+            ..Self::default()
+        }
+    }
+
+    /// Show the key bindings for the application mode and screen, greying out
+    /// those the role is not permitted
+    pub fn show(
+        &mut self,
+        app_mode: &domain::AppMode,
+        screen: &domain::Screen,
+        role: &domain::UserRole,
+    ) {
+        let bindings: Vec<&domain::KeyBinding> = self
+            .keymap
+            .bindings(app_mode)
+            .iter()
+            .filter(|binding| binding.action != domain::Action::Nil)
+            .filter(|binding| binding.action.is_relevant_to(screen))
+            .collect();
+
+        let mut lines = Vec::new();
+        for category in CATEGORIES {
+            let in_category = bindings
+                .iter()
+                .filter(|binding| binding.action.category() == category);
+
+            let mut category_lines: Vec<text::Line> = in_category
+                .map(|binding| {
+                    let mut description = binding.action.description();
+                    let mut style = style::Style::default();
+                    if !role.is_permitted(&binding.action) {
+                        description.push_str(" (admin)");
+                        style = self.theme.style("text.dimmed");
+                    }
+                    text::Line::styled(
+                        format!("  {:<14}{description}", binding.keys_label()),
+                        style,
+                    )
+                })
+                .collect();
+
+            if category_lines.is_empty() {
+                continue;
+            }
+
+            if !lines.is_empty() {
+                lines.push(text::Line::default());
+            }
+            lines.push(text::Line::styled(
+                category,
+                self.theme.style("table.header"),
+            ));
+            lines.append(&mut category_lines);
+        }
+
+        // Typing in input mode is not a binding, but is the main thing to do
+        if *app_mode == domain::AppMode::Input {
+            lines.push(text::Line::default());
+            lines.push(text::Line::from("  Other keys are typed into the form"));
+        }
+
+        self.title = format!(" Help: {app_mode} mode on {screen} ");
+        self.lines = lines;
+        self.scroll = 0;
+    }
+
+    /// Is the help being shown
+    fn is_open(&self) -> bool {
+        !self.lines.is_empty()
+    }
+
+    /// Hide the help
+    fn close(&mut self) {
+        self.lines.clear();
+    }
+
+    /// Scroll by a number of lines, up if negative, staying within the help
+    fn scroll_by(&mut self, lines: i32) {
+        let last_line = self.lines.len().saturating_sub(1) as i32;
+        self.scroll = (self.scroll as i32 + lines).clamp(0, last_line) as u16;
+    }
+}
+
+impl ui::components::Component for HelpComponent {
+    fn handle_key(
+        &mut self,
+        key_event: crossterm::KeyEvent,
+    ) -> Result<Option<domain::Action>> {
+        // Leave the key to the key map while closed
+        if !self.is_open() {
+            return Ok(None);
+        }
+
+        match key_event.code {
+            crossterm::KeyCode::Esc
+            | crossterm::KeyCode::Char('q')
+            | crossterm::KeyCode::Char('?')
+            | crossterm::KeyCode::F(1) => self.close(),
+            crossterm::KeyCode::Down | crossterm::KeyCode::Char('j') => {
+                self.scroll_by(1)
+            }
+            crossterm::KeyCode::Up | crossterm::KeyCode::Char('k') => {
+                self.scroll_by(-1)
+            }
+            crossterm::KeyCode::PageDown => self.scroll_by(10),
+            crossterm::KeyCode::PageUp => self.scroll_by(-10),
+            _ => {}
+        }
+
+        // Consume every key while open, so nothing below the help sees it
+        Ok(Some(domain::Action::Nil))
+    }
+
+    fn handle_mouse(
+        &mut self,
+        mouse_event: crossterm::MouseEvent,
+    ) -> Result<Option<domain::Action>> {
+        if !self.is_open() {
+            return Ok(None);
+        }
+
+        match mouse_event.kind {
+            crossterm::MouseEventKind::ScrollDown => self.scroll_by(3),
+            crossterm::MouseEventKind::ScrollUp => self.scroll_by(-3),
+            _ => {}
+        }
+
+        // Consume every mouse event while open, as with keys
+        Ok(Some(domain::Action::Nil))
+    }
+
+    fn render(&mut self, frame: &mut ratatui::Frame, area: layout::Rect) {
+        if !self.is_open() {
+            return;
+        }
+
+        let area = ui::helpers::overlay(60, 80, area);
+        frame.render_widget(widgets::Clear, area);
+
+        let block = widgets::Block::bordered()
+            .title(self.title.clone())
+            .title_bottom(
+                text::Line::from(" ↑/↓ scroll | Esc close ").right_aligned(),
+            );
+
+        let paragraph = widgets::Paragraph::new(self.lines.clone())
+            .block(block)
+            .scroll((self.scroll, 0));
+
+        frame.render_widget(paragraph, area);
+    }
+}
@@ -0,0 +1,169 @@
+//-- ./src/ui/components/mod.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! Self-contained ui components.
+//!
+//! A component keeps its own state, updated from the actions passed to it,
+//! and renders itself. New components are added to [`Components`] and are
+//! then passed every action by `App::update`.
+//!
+//! # Reference
+//!
+//! - [Ratatui component template](https://github.com/ratatui/templates/tree/main/component)
+//! ---
+
+use crossterm::event as crossterm;
+use ratatui::layout;
+use tokio::sync::mpsc;
+
+use crate::{domain, prelude::*, ui, Config};
+
+mod footer;
+pub use footer::FooterComponent;
+
+mod help;
+pub use help::HelpComponent;
+
+mod modal;
+pub use modal::ModalComponent;
+
+mod notifications;
+pub use notifications::NotificationsComponent;
+
+mod palette;
+pub use palette::CommandPaletteComponent;
+
+mod statistics;
+pub use statistics::StatisticsComponent;
+
+/// Display toast messages
+mod toast;
+pub use toast::ToastComponent;
+
+/// A self-contained ui component
+pub trait Component {
+    /// Register the action sender, so the component can send actions outside
+    /// of `handle_key` and `update`
+    fn init(
+        &mut self,
+        action_sender: mpsc::UnboundedSender<domain::Action>,
+    ) -> Result<()> {
+        let _ = action_sender;
+        Ok(())
+    }
+
+    /// Handle a key event before it is mapped to an action, returning an
+    /// action if the component consumed the key
+    fn handle_key(
+        &mut self,
+        key_event: crossterm::KeyEvent,
+    ) -> Result<Option<domain::Action>> {
+        let _ = key_event;
+        Ok(None)
+    }
+
+    /// Handle a mouse event before it is mapped to an action, returning an
+    /// action if the component consumed the event
+    fn handle_mouse(
+        &mut self,
+        mouse_event: crossterm::MouseEvent,
+    ) -> Result<Option<domain::Action>> {
+        let _ = mouse_event;
+        Ok(None)
+    }
+
+    /// Update the component state with an action, returning a follow up action
+    /// to add to the queue
+    fn update(&mut self, action: &domain::Action) -> Result<Option<domain::Action>> {
+        let _ = action;
+        Ok(None)
+    }
+
+    /// Render the component in the area
+    fn render(&mut self, frame: &mut ratatui::Frame, area: layout::Rect);
+}
+
+/// The components the TUI application is composed of
+pub struct Components {
+    /// Modal dialogs above every other layer, offered keys first while open
+    pub modal: ModalComponent,
+
+    /// Key binding help overlay, offered keys first while open
+    pub help: HelpComponent,
+
+    /// Command palette overlay, offered keys first while open
+    pub palette: CommandPaletteComponent,
+
+    /// Notification history overlay, offered keys first while open
+    pub notifications: NotificationsComponent,
+
+    /// Footer status bar
+    pub footer: FooterComponent,
+
+    /// Toast messages in the top right corner
+    pub toast: ToastComponent,
+
+    /// Tick and frame rate statistics
+    pub statistics: StatisticsComponent,
+}
+
+impl Components {
+    /// Construct the application components
+    pub fn new(config: &Config, keymap: &domain::Keymap, theme: &ui::Theme) -> Self {
+        let modal = ModalComponent::new(theme.clone());
+        let help = HelpComponent::new(keymap.clone(), theme.clone());
+        let palette = CommandPaletteComponent::new(theme.clone());
+        let notifications = NotificationsComponent::new(theme.clone(), &config.toast);
+        let endpoint = config.backend.address().to_string();
+        let footer = FooterComponent::new(
+            theme.clone(),
+            endpoint,
+            config.backend.degraded_latency(),
+        );
+        let follow_up_key = keymap
+            .bindings(&domain::AppMode::Normal)
+            .iter()
+            .find(|binding| binding.action == domain::Action::ToastFollowUp)
+            .map(domain::KeyBinding::keys_label);
+        let toast = ToastComponent::new(theme.clone(), config.toast.clone(), follow_up_key);
+        let statistics =
+            StatisticsComponent::new(config.app.show_statistics, theme.clone());
+
+        Self {
+            modal,
+            help,
+            palette,
+            notifications,
+            footer,
+            toast,
+            statistics,
+        }
+    }
+
+    /// Iterate over every component, in the order actions are passed to them
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut dyn Component> {
+        let components: [&mut dyn Component; 7] = [
+            &mut self.modal,
+            &mut self.help,
+            &mut self.palette,
+            &mut self.notifications,
+            &mut self.footer,
+            &mut self.toast,
+            &mut self.statistics,
+        ];
+        components.into_iter()
+    }
+
+    /// Register the action sender with every component
+    pub fn init(
+        &mut self,
+        action_sender: &mpsc::UnboundedSender<domain::Action>,
+    ) -> Result<()> {
+        for component in self.iter_mut() {
+            component.init(action_sender.clone())?;
+        }
+
+        Ok(())
+    }
+}
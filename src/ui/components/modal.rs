@@ -0,0 +1,426 @@
+//-- ./src/ui/components/modal.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Modal dialog component
+//!
+//! Shows confirm, prompt, selection and error detail dialogs above every
+//! other layer. Dialogs stack, the newest on top, and while any is open every
+//! key and mouse event is kept from the screens below. Focus is trapped in the
+//! top dialog, with `Tab` cycling between its input or list and its buttons.
+//! Answering a dialog closes it and sends its result as an action.
+//! ---
+
+use crossterm::event as crossterm;
+use ratatui::{layout, text, widgets};
+use tui_input::Input;
+
+use crate::{domain, prelude::*, ui};
+
+/// The most selection options shown at once
+const MAX_OPTIONS_SHOWN: u16 = 8;
+
+/// An open dialog, with the operator's answer so far
+#[derive(Debug, Clone)]
+struct OpenDialog {
+    /// The dialog being answered
+    dialog: domain::Dialog,
+
+    /// Text typed into a prompt dialog
+    input: Input,
+
+    /// The selected option of a selection dialog
+    selected: usize,
+
+    /// The focused element, the input or list first if the dialog has one,
+    /// then the buttons
+    focus: usize,
+}
+
+impl OpenDialog {
+    /// Open a dialog, focusing the input or list, or the safest button
+    fn new(dialog: domain::Dialog) -> Self {
+        // Default a confirm dialog to No, so a stray Enter does nothing
+        let focus = match dialog.kind {
+            domain::DialogKind::Confirm { .. } => 1,
+            _ => 0,
+        };
+
+        Self {
+            dialog,
+            input: Input::default(),
+            selected: 0,
+            focus,
+        }
+    }
+
+    /// Does the dialog have an input or list before its buttons
+    fn has_body(&self) -> bool {
+        matches!(
+            self.dialog.kind,
+            domain::DialogKind::Prompt { .. } | domain::DialogKind::Select { .. }
+        )
+    }
+
+    /// Is the input or list in focus
+    fn is_body_focused(&self) -> bool {
+        self.has_body() && self.focus == 0
+    }
+
+    /// The button in focus, if any
+    fn focused_button(&self) -> Option<&'static str> {
+        let index = self.focus.checked_sub(self.has_body() as usize)?;
+        self.dialog.buttons().get(index).copied()
+    }
+
+    /// Move focus forward or back, wrapping within the dialog
+    fn move_focus(&mut self, forward: bool) {
+        let len = self.dialog.buttons().len() + self.has_body() as usize;
+        self.focus = if forward {
+            (self.focus + 1) % len
+        } else {
+            (self.focus + len - 1) % len
+        };
+    }
+
+    /// Move the selected option of a selection dialog up or down
+    fn move_selection(&mut self, forward: bool) {
+        if let domain::DialogKind::Select { options, .. } = &self.dialog.kind {
+            let last = options.len().saturating_sub(1);
+            self.selected = match forward {
+                true => (self.selected + 1).min(last),
+                false => self.selected.saturating_sub(1),
+            };
+        }
+    }
+
+    /// The action answering the dialog, or `None` if there is no answer yet,
+    /// i.e. an empty prompt
+    fn answer(&self) -> Option<domain::Action> {
+        match &self.dialog.kind {
+            domain::DialogKind::Confirm { on_yes } => Some(*on_yes.clone()),
+            domain::DialogKind::Prompt { on_submit } => {
+                let value = self.input.value().trim();
+                (!value.is_empty()).then(|| on_submit(value.to_string()))
+            }
+            domain::DialogKind::Select { options, on_select } => {
+                options.get(self.selected).cloned().map(on_select)
+            }
+            domain::DialogKind::ErrorDetail => Some(domain::Action::Nil),
+        }
+    }
+}
+
+/// Modal dialog component
+#[derive(Debug, Clone, Default)]
+pub struct ModalComponent {
+    /// Open dialogs, the top dialog last
+    dialogs: Vec<OpenDialog>,
+
+    /// Named styles to render with
+    theme: ui::Theme,
+}
+
+impl ModalComponent {
+    /// Construct the modal layer with no dialogs open
+    pub fn new(theme: ui::Theme) -> Self {
+        Self {
+            theme,
+            ..Self::default()
+        }
+    }
+
+    /// Is a dialog open
+    pub fn is_open(&self) -> bool {
+        !self.dialogs.is_empty()
+    }
+
+    /// Close the top dialog, answering it if the answer is ready
+    fn submit(&mut self) -> Option<domain::Action> {
+        let answer = self.dialogs.last()?.answer()?;
+        self.dialogs.pop();
+        Some(answer)
+    }
+
+    /// Activate the element in focus of the top dialog
+    fn activate(&mut self) -> Option<domain::Action> {
+        let top = self.dialogs.last()?;
+        if top.is_body_focused() {
+            return self.submit();
+        }
+
+        match top.focused_button() {
+            Some("Yes" | "Select") => self.submit(),
+            Some("OK") if top.has_body() => self.submit(),
+            _ => {
+                self.dialogs.pop();
+                None
+            }
+        }
+    }
+
+    /// Handle a key in the top dialog
+    fn handle_dialog_key(
+        &mut self,
+        key_event: crossterm::KeyEvent,
+    ) -> Option<domain::Action> {
+        let top = self.dialogs.last_mut()?;
+        let is_confirm =
+            matches!(top.dialog.kind, domain::DialogKind::Confirm { .. });
+        let is_select = matches!(top.dialog.kind, domain::DialogKind::Select { .. });
+
+        match key_event.code {
+            crossterm::KeyCode::Esc => {
+                self.dialogs.pop();
+            }
+            crossterm::KeyCode::Enter => return self.activate(),
+
+            // Keep focus within the dialog
+            crossterm::KeyCode::Tab => top.move_focus(true),
+            crossterm::KeyCode::BackTab => top.move_focus(false),
+
+            // Answer a confirm dialog straight away
+            crossterm::KeyCode::Char('y') if is_confirm => return self.submit(),
+            crossterm::KeyCode::Char('n') if is_confirm => {
+                self.dialogs.pop();
+            }
+
+            // Step through the options of a selection dialog
+            crossterm::KeyCode::Down | crossterm::KeyCode::Char('j')
+                if is_select && top.is_body_focused() =>
+            {
+                top.move_selection(true)
+            }
+            crossterm::KeyCode::Up | crossterm::KeyCode::Char('k')
+                if is_select && top.is_body_focused() =>
+            {
+                top.move_selection(false)
+            }
+
+            // Type into a prompt dialog
+            _ if top.is_body_focused() && !is_select => {
+                if let Some(request) = ui::helpers::input_request(key_event) {
+                    top.input.handle(request);
+                }
+            }
+
+            // Step between the buttons
+            crossterm::KeyCode::Right | crossterm::KeyCode::Char('l') => {
+                top.move_focus(true)
+            }
+            crossterm::KeyCode::Left | crossterm::KeyCode::Char('h') => {
+                top.move_focus(false)
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    /// Render a dialog in the middle of the area
+    fn render_dialog(
+        &self,
+        open: &OpenDialog,
+        frame: &mut ratatui::Frame,
+        area: layout::Rect,
+    ) {
+        //-- 1. Size the dialog to its message and input or list
+        let width =
+            ui::Breakpoint::of(area).pick(area.width.saturating_sub(2), 60, 72);
+        let message_width = width.saturating_sub(4).max(1) as usize;
+        let message_height = open
+            .dialog
+            .message
+            .lines()
+            .map(|line| line.len().div_ceil(message_width).max(1) as u16)
+            .sum::<u16>();
+        let body_height = match &open.dialog.kind {
+            domain::DialogKind::Prompt { .. } => 2,
+            domain::DialogKind::Select { options, .. } => {
+                (options.len() as u16).min(MAX_OPTIONS_SHOWN) + 1
+            }
+            _ => 0,
+        };
+        let height = message_height + body_height + 4;
+        let area = ui::helpers::centered(width, height, area);
+
+        frame.render_widget(widgets::Clear, area);
+        let block = widgets::Block::bordered()
+            .title(format!(" {} ", open.dialog.title))
+            .title_bottom(
+                text::Line::from(" Tab focus | Esc cancel ").right_aligned(),
+            );
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [message_area, body_area, buttons_area] = layout::Layout::vertical([
+            layout::Constraint::Length(message_height),
+            layout::Constraint::Length(body_height),
+            layout::Constraint::Length(2),
+        ])
+        .areas(inner_area);
+
+        //-- 2. Render the message
+        let message = widgets::Paragraph::new(open.dialog.message.clone())
+            .wrap(widgets::Wrap { trim: false });
+        frame.render_widget(message, message_area);
+
+        //-- 3. Render the prompt input or the options
+        let body_style = match open.is_body_focused() {
+            true => self.theme.style("form.focus"),
+            false => self.theme.style("text.dimmed"),
+        };
+        match &open.dialog.kind {
+            domain::DialogKind::Prompt { .. } => {
+                let input_area = layout::Rect {
+                    y: body_area.y + 1,
+                    height: 1,
+                    ..body_area
+                };
+                let line = format!("> {}", open.input.value());
+                frame.render_widget(
+                    widgets::Paragraph::new(line).style(body_style),
+                    input_area,
+                );
+                if open.is_body_focused() {
+                    frame.set_cursor_position(layout::Position::new(
+                        input_area.x + 2 + open.input.visual_cursor() as u16,
+                        input_area.y,
+                    ));
+                }
+            }
+            domain::DialogKind::Select { options, .. } => {
+                let list_area = layout::Rect {
+                    y: body_area.y + 1,
+                    height: body_area.height.saturating_sub(1),
+                    ..body_area
+                };
+                let items = options
+                    .iter()
+                    .map(|option| text::Line::from(option.clone()));
+                let list = widgets::List::new(items)
+                    .style(body_style)
+                    .highlight_style(self.theme.style("table.selected"));
+                let mut list_state =
+                    widgets::ListState::default().with_selected(Some(open.selected));
+                frame.render_stateful_widget(list, list_area, &mut list_state);
+            }
+            _ => {}
+        }
+
+        //-- 4. Render the buttons, highlighting the one in focus
+        let buttons: Vec<text::Span> = open
+            .dialog
+            .buttons()
+            .iter()
+            .flat_map(|button| {
+                let style = match open.focused_button() == Some(button) {
+                    true => self.theme.style("table.selected"),
+                    false => self.theme.style("text.dimmed"),
+                };
+                [
+                    text::Span::styled(format!("[ {button} ]"), style),
+                    text::Span::raw(" "),
+                ]
+            })
+            .collect();
+        let buttons_area = layout::Rect {
+            y: buttons_area.y + 1,
+            height: 1,
+            ..buttons_area
+        };
+        frame.render_widget(text::Line::from(buttons).right_aligned(), buttons_area);
+    }
+}
+
+impl ui::components::Component for ModalComponent {
+    fn handle_key(
+        &mut self,
+        key_event: crossterm::KeyEvent,
+    ) -> Result<Option<domain::Action>> {
+        // Leave the key to the layers below while no dialog is open
+        if !self.is_open() {
+            return Ok(None);
+        }
+
+        // Block every key from the layers below, sending the answer if any
+        let answer = self.handle_dialog_key(key_event);
+        Ok(Some(answer.unwrap_or(domain::Action::Nil)))
+    }
+
+    fn handle_mouse(
+        &mut self,
+        mouse_event: crossterm::MouseEvent,
+    ) -> Result<Option<domain::Action>> {
+        let _ = mouse_event;
+
+        // Block every mouse event from the layers below, as with keys
+        Ok(self.is_open().then_some(domain::Action::Nil))
+    }
+
+    fn update(&mut self, action: &domain::Action) -> Result<Option<domain::Action>> {
+        // Open the dialog above any already open
+        if let domain::Action::Dialog(dialog) = action {
+            self.dialogs.push(OpenDialog::new(dialog.clone()));
+        }
+
+        Ok(None)
+    }
+
+    fn render(&mut self, frame: &mut ratatui::Frame, area: layout::Rect) {
+        // Render the stack bottom up, so the top dialog is drawn last
+        for open in &self.dialogs {
+            self.render_dialog(open, frame, area);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+    use ui::components::Component;
+
+    /// A key press without modifiers
+    fn key(code: crossterm::KeyCode) -> crossterm::KeyEvent {
+        crossterm::KeyEvent::new(code, crossterm::KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn dialogs_stack_trap_focus_and_answer_with_an_action() {
+        //-- Setup and Fixtures (Arrange)
+        let mut modal = ModalComponent::default();
+        let confirm =
+            domain::Dialog::confirm("Quit", "Quit now?", domain::Action::Quit);
+        let prompt = domain::Dialog::prompt(
+            "Revoke sessions",
+            "Revoke sessions for email",
+            domain::Action::RevokeSessions,
+        );
+        modal.update(&domain::Action::Dialog(confirm)).unwrap();
+        modal.update(&domain::Action::Dialog(prompt)).unwrap();
+
+        //-- Execute Function (Act)
+        let typed = modal
+            .handle_key(key(crossterm::KeyCode::Char('y')))
+            .unwrap();
+        modal.handle_key(key(crossterm::KeyCode::Tab)).unwrap();
+        modal.handle_key(key(crossterm::KeyCode::Tab)).unwrap();
+        modal.handle_key(key(crossterm::KeyCode::Tab)).unwrap();
+        let submitted = modal.handle_key(key(crossterm::KeyCode::Enter)).unwrap();
+        let confirmed = modal
+            .handle_key(key(crossterm::KeyCode::Char('y')))
+            .unwrap();
+
+        //-- Checks (Assertions)
+        // The `y` is typed into the prompt rather than answering the confirm
+        // below it, and focus wraps back round to the input
+        assert_eq!(typed, Some(domain::Action::Nil));
+        assert_eq!(
+            submitted,
+            Some(domain::Action::RevokeSessions("y".to_string()))
+        );
+        assert_eq!(confirmed, Some(domain::Action::Quit));
+        assert!(!modal.is_open());
+    }
+}
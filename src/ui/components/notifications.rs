@@ -0,0 +1,238 @@
+//-- ./src/ui/components/notifications.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Notification history component
+//!
+//! Keeps a bounded history of every toast message raised and shows it in an
+//! overlay panel, filtered by toast kind. The selected message can be copied
+//! to the clipboard. Errors and warnings raised while the panel is closed are
+//! counted as unread until it is next opened.
+//! ---
+
+use crossterm::event as crossterm;
+use ratatui::{layout, text, widgets};
+
+use crate::{config, domain, prelude::*, state, ui};
+
+/// The kind filters, cycled through in order. `None` shows every kind.
+const FILTERS: [Option<domain::ToastKind>; 6] = [
+    None,
+    Some(domain::ToastKind::Error),
+    Some(domain::ToastKind::Warning),
+    Some(domain::ToastKind::Info),
+    Some(domain::ToastKind::Success),
+    Some(domain::ToastKind::Notification),
+];
+
+/// Notification history overlay component
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NotificationsComponent {
+    /// Past toast messages
+    state: state::NotificationsState,
+
+    /// Is the panel being shown
+    is_open: bool,
+
+    /// Index into the kind filters
+    filter: usize,
+
+    /// The selected notification of those shown
+    table: widgets::TableState,
+
+    /// The unread count last sent, so only changes are sent
+    unread: usize,
+
+    /// Named styles to render with
+    theme: ui::Theme,
+}
+
+impl NotificationsComponent {
+    /// Construct the notification history, keeping up to the configured number
+    /// of toast messages
+    pub fn new(theme: ui::Theme, config: &config::ToastConfig) -> Self {
+        Self {
+            state: state::NotificationsState::new(config.history_size),
+            theme,
+            ..Self::default()
+        }
+    }
+
+    /// The notifications shown with the current kind filter
+    fn filtered(&self) -> Vec<&state::Notification> {
+        self.state.filtered(FILTERS[self.filter].as_ref())
+    }
+
+    /// Show the panel, marking every notification as read
+    fn open(&mut self) {
+        self.is_open = true;
+        self.filter = 0;
+        self.table.select(Some(0));
+        self.state.mark_read();
+    }
+
+    /// Move the selection by a number of rows, up if negative
+    fn move_selection(&mut self, rows: isize) {
+        let len = self.filtered().len();
+        if len == 0 {
+            return;
+        }
+        let selected = self.table.selected().unwrap_or(0) as isize;
+        let row = (selected + rows).clamp(0, len as isize - 1);
+        self.table.select(Some(row as usize));
+    }
+
+    /// Show the next kind filter, selecting the newest notification
+    fn next_filter(&mut self) {
+        self.filter = (self.filter + 1) % FILTERS.len();
+        self.table.select(Some(0));
+    }
+
+    /// Send the unread count if it changed since last sent
+    fn unread_changed(&mut self) -> Option<domain::Action> {
+        let unread = self.state.unread();
+        if unread == self.unread {
+            return None;
+        }
+        self.unread = unread;
+        Some(domain::Action::NotificationsUnread(unread))
+    }
+}
+
+impl ui::components::Component for NotificationsComponent {
+    fn handle_key(
+        &mut self,
+        key_event: crossterm::KeyEvent,
+    ) -> Result<Option<domain::Action>> {
+        // Leave the key to the key map while closed
+        if !self.is_open {
+            return Ok(None);
+        }
+
+        match key_event.code {
+            crossterm::KeyCode::Esc | crossterm::KeyCode::Char('q') => {
+                self.is_open = false
+            }
+            crossterm::KeyCode::Down | crossterm::KeyCode::Char('j') => {
+                self.move_selection(1)
+            }
+            crossterm::KeyCode::Up | crossterm::KeyCode::Char('k') => {
+                self.move_selection(-1)
+            }
+            crossterm::KeyCode::PageDown => self.move_selection(10),
+            crossterm::KeyCode::PageUp => self.move_selection(-10),
+            crossterm::KeyCode::Char('f') | crossterm::KeyCode::Tab => {
+                self.next_filter()
+            }
+
+            // Show the whole of the selected message
+            crossterm::KeyCode::Enter => {
+                let selected = self.table.selected().unwrap_or(0);
+                if let Some(notification) = self.filtered().get(selected) {
+                    let title = format!(
+                        "{} at {}",
+                        notification.kind,
+                        notification.at.format("%d %b %H:%M:%S")
+                    );
+                    let dialog =
+                        domain::Dialog::error_detail(title, notification.message.clone());
+                    return Ok(Some(domain::Action::Dialog(dialog)));
+                }
+            }
+
+            // Copy the selected message
+            crossterm::KeyCode::Char('y') | crossterm::KeyCode::Char('c') => {
+                let selected = self.table.selected().unwrap_or(0);
+                if let Some(notification) = self.filtered().get(selected) {
+                    let message = notification.message.clone();
+                    return Ok(Some(domain::Action::Copy(message)));
+                }
+            }
+            _ => {}
+        }
+
+        // Consume every key while open, so nothing below the panel sees it
+        Ok(Some(domain::Action::Nil))
+    }
+
+    fn handle_mouse(
+        &mut self,
+        mouse_event: crossterm::MouseEvent,
+    ) -> Result<Option<domain::Action>> {
+        if !self.is_open {
+            return Ok(None);
+        }
+
+        match mouse_event.kind {
+            crossterm::MouseEventKind::ScrollDown => self.move_selection(1),
+            crossterm::MouseEventKind::ScrollUp => self.move_selection(-1),
+            _ => {}
+        }
+
+        // Consume every mouse event while open, as with keys
+        Ok(Some(domain::Action::Nil))
+    }
+
+    fn update(&mut self, action: &domain::Action) -> Result<Option<domain::Action>> {
+        match action {
+            // Keep every toast message, already read if the panel is open
+            domain::Action::Toast(toast) => self.state.push(toast, self.is_open),
+
+            // Show the history
+            domain::Action::Notifications => self.open(),
+
+            _ => return Ok(None),
+        }
+
+        Ok(self.unread_changed())
+    }
+
+    fn render(&mut self, frame: &mut ratatui::Frame, area: layout::Rect) {
+        if !self.is_open {
+            return;
+        }
+
+        let area = ui::helpers::overlay(80, 70, area);
+        frame.render_widget(widgets::Clear, area);
+
+        // Title the panel with the filter and how many notifications match
+        let filter = match &FILTERS[self.filter] {
+            Some(kind) => kind.to_string(),
+            None => "All".to_string(),
+        };
+        let notifications = self.state.filtered(FILTERS[self.filter].as_ref());
+        let title = format!(" Notifications: {filter} ({}) ", notifications.len());
+        let block = widgets::Block::bordered().title(title).title_bottom(
+            text::Line::from( " ↑/↓ select | Enter detail | f filter | y copy | Esc close ")
+                .right_aligned(),
+        );
+
+        let header = widgets::Row::new(["Time", "Kind", "Message"])
+            .style(self.theme.style("table.header"));
+
+        let rows = notifications.iter().map(|notification| {
+            widgets::Row::new([
+                text::Span::raw(
+                    notification.at.format("%d %b %H:%M:%S").to_string(),
+                ),
+                text::Span::styled(
+                    notification.kind.to_string(),
+                    self.theme.toast(&notification.kind),
+                ),
+                text::Span::raw(notification.message.clone()),
+            ])
+        });
+
+        let widths = [
+            layout::Constraint::Length(15),
+            layout::Constraint::Length(12),
+            layout::Constraint::Fill(1),
+        ];
+        let table = widgets::Table::new(rows, widths)
+            .header(header)
+            .block(block)
+            .highlight_style(self.theme.style("table.selected"));
+
+        frame.render_stateful_widget(table, area, &mut self.table);
+    }
+}
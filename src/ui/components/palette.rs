@@ -0,0 +1,539 @@
+//-- ./src/ui/components/palette.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Command palette component
+//!
+//! An overlay, opened with `:` or `Ctrl-P`, listing every command with a
+//! description. Typing fuzzy filters the list and `Enter` sends the selected
+//! command's action through the action queue. Commands that need an argument,
+//! such as an email address, prompt for it before the action is sent.
+//! ---
+
+use std::cmp;
+
+use crossterm::event as crossterm;
+use ratatui::{layout, text, widgets};
+use tui_input::Input;
+
+use crate::{domain, prelude::*, ui};
+
+/// What a command does when selected
+#[derive(Debug, Clone)]
+enum CommandKind {
+    /// Send the action straight away
+    Action(domain::Action),
+
+    /// Prompt for an argument, then send the action built from it
+    Prompt {
+        prompt: &'static str,
+        action: fn(String) -> domain::Action,
+    },
+}
+
+/// A command listed in the palette
+#[derive(Debug, Clone)]
+struct Command {
+    /// Name the command is filtered by
+    name: String,
+
+    /// Describe what the command does
+    description: String,
+
+    /// What the command does when selected
+    kind: CommandKind,
+
+    /// Only available to operators with the Admin role
+    is_admin_only: bool,
+}
+
+impl Command {
+    /// A command that sends the action straight away
+    fn action(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        action: domain::Action,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            is_admin_only: action.is_admin_only(),
+            kind: CommandKind::Action(action),
+        }
+    }
+
+    /// A command that prompts for an argument before sending the action
+    fn prompt(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        prompt: &'static str,
+        action: fn(String) -> domain::Action,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            is_admin_only: action(String::new()).is_admin_only(),
+            kind: CommandKind::Prompt { prompt, action },
+        }
+    }
+}
+
+/// Every command listed in the palette
+fn commands() -> Vec<Command> {
+    let mut commands: Vec<Command> = domain::Screen::all()
+        .map(|screen| {
+            Command::action(
+                format!("Go to {screen}"),
+                format!("Show the {screen} screen"),
+                domain::Action::Navigate(screen),
+            )
+        })
+        .collect();
+
+    commands.extend([
+        Command::action(
+            "Back",
+            "Go back to the previous screen",
+            domain::Action::NavigateBack,
+        ),
+        Command::action(
+            "Forward",
+            "Go forward to the screen gone back from",
+            domain::Action::NavigateForward,
+        ),
+        Command::action(
+            "Edit form",
+            "Switch to input mode to edit the form being shown",
+            domain::Action::AppMode(domain::AppMode::Input),
+        ),
+        Command::action(
+            "Edit field in editor",
+            "Edit the form field in focus in $VISUAL or $EDITOR",
+            domain::Action::FormEdit,
+        ),
+        Command::action(
+            "Submit form",
+            "Submit the form being shown",
+            domain::Action::FormSubmit,
+        ),
+        Command::action(
+            "Log out",
+            "Forget the access token and return to the login screen",
+            domain::Action::Logout,
+        ),
+        Command::action(
+            "Check backend status",
+            "Ping the backend server",
+            domain::Action::BackendStatusUpdate,
+        ),
+        Command::action(
+            "Watch logins",
+            "Check the logins for new IP addresses now",
+            domain::Action::LoginsWatch,
+        ),
+        Command::action(
+            "Audit log previous day",
+            "Show the audit log entries for the previous day",
+            domain::Action::AuditLogPreviousDay,
+        ),
+        Command::action(
+            "Audit log next day",
+            "Show the audit log entries for the next day",
+            domain::Action::AuditLogNextDay,
+        ),
+        Command::action(
+            "Switch pane focus",
+            "Move focus between the list and the detail of its selected row",
+            domain::Action::SplitFocus,
+        ),
+        Command::action(
+            "Flip split",
+            "Show the list and detail side by side or stacked",
+            domain::Action::SplitFlip,
+        ),
+        Command::prompt(
+            "Revoke sessions",
+            "Revoke every session of a user, forcing them to log in again",
+            "Revoke sessions for email",
+            confirm_revoke_sessions,
+        ),
+        Command::action(
+            "Clear toast",
+            "Dismiss the toast message being shown",
+            domain::Action::ClearToast,
+        ),
+        Command::action(
+            "Notifications",
+            "Browse the history of past toast messages",
+            domain::Action::Notifications,
+        ),
+        Command::action(
+            "Help",
+            "Show the key bindings for this screen",
+            domain::Action::Help,
+        ),
+        Command::action(
+            "Suspend",
+            "Suspend to the shell, resume with fg",
+            domain::Action::Suspend,
+        ),
+        Command::action("Quit", "Exit the application", domain::Action::Quit),
+    ]);
+
+    commands
+}
+
+/// Ask the operator to confirm revoking the sessions before the RPC is made
+fn confirm_revoke_sessions(email: String) -> domain::Action {
+    domain::Action::RevokeSessions(email).confirmed()
+}
+
+/// Score how well the query fuzzy matches the candidate, ignoring case.
+///
+/// Every query character must appear in the candidate in order. Matches that
+/// are consecutive or start a word score higher. Returns `None` if the query
+/// does not match.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for query_char in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        // Find the next candidate character matching the query character
+        let offset = candidate[position..]
+            .iter()
+            .position(|c| *c == query_char)?;
+        let index = position + offset;
+
+        score += 1;
+        if previous_match.is_some_and(|previous| previous + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || candidate[index - 1].is_whitespace() {
+            score += 3;
+        }
+        // Penalise the characters skipped between matches
+        score -= offset as i64;
+
+        previous_match = Some(index);
+        position = index + 1;
+    }
+
+    Some(score)
+}
+
+/// Command palette component
+#[derive(Debug, Default)]
+pub struct CommandPaletteComponent {
+    /// The palette is being shown
+    is_open: bool,
+
+    /// The filter query typed by the operator
+    query: Input,
+
+    /// Index, in the filtered commands, of the selected command
+    selected: usize,
+
+    /// The command prompting for an argument, and the argument input
+    prompting: Option<(Command, Input)>,
+
+    /// Named styles to render with
+    theme: ui::Theme,
+}
+
+impl CommandPaletteComponent {
+    /// Construct the command palette, closed
+    pub fn new(theme: ui::Theme) -> Self {
+        Self {
+            theme,
+            ..Self::default()
+        }
+    }
+
+    /// The commands matching the query, best match first
+    fn filtered(&self) -> Vec<Command> {
+        let mut scored: Vec<(i64, Command)> = commands()
+            .into_iter()
+            .filter_map(|command| {
+                fuzzy_score(self.query.value(), &command.name)
+                    .map(|score| (score, command))
+            })
+            .collect();
+
+        // Stable sort, so equal scores keep the listed order
+        scored.sort_by_key(|(score, _)| cmp::Reverse(*score));
+
+        scored.into_iter().map(|(_, command)| command).collect()
+    }
+
+    /// Show the palette with an empty query
+    fn open(&mut self) {
+        self.close();
+        self.is_open = true;
+    }
+
+    /// Hide the palette
+    fn close(&mut self) {
+        self.is_open = false;
+        self.query.reset();
+        self.selected = 0;
+        self.prompting = None;
+    }
+
+    /// Select the command, returning its action or prompting for its argument
+    fn select(&mut self, command: Command) -> Option<domain::Action> {
+        match command.kind {
+            CommandKind::Action(ref action) => {
+                let action = action.clone();
+                self.close();
+                Some(action)
+            }
+            CommandKind::Prompt { .. } => {
+                self.prompting = Some((command, Input::default()));
+                None
+            }
+        }
+    }
+
+    /// Handle a key while prompting for a command argument
+    fn handle_prompt_key(
+        &mut self,
+        key_event: crossterm::KeyEvent,
+    ) -> Option<domain::Action> {
+        let (command, input) = self.prompting.as_mut()?;
+
+        match key_event.code {
+            // Back to the command list
+            crossterm::KeyCode::Esc => self.prompting = None,
+
+            // Send the action built from the argument
+            crossterm::KeyCode::Enter => {
+                let argument = input.value().trim().to_string();
+                if argument.is_empty() {
+                    return None;
+                }
+                let CommandKind::Prompt { action, .. } = command.kind else {
+                    return None;
+                };
+                self.close();
+                return Some(action(argument));
+            }
+
+            _ => {
+                if let Some(request) = ui::helpers::input_request(key_event) {
+                    input.handle(request);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Handle a key while showing the command list
+    fn handle_list_key(
+        &mut self,
+        key_event: crossterm::KeyEvent,
+    ) -> Option<domain::Action> {
+        let is_ctrl = key_event
+            .modifiers
+            .contains(crossterm::KeyModifiers::CONTROL);
+        let filtered = self.filtered();
+
+        match key_event.code {
+            crossterm::KeyCode::Esc => self.close(),
+
+            // Move the selection, wrapping at either end
+            crossterm::KeyCode::Down | crossterm::KeyCode::Tab => {
+                self.selected = (self.selected + 1) % filtered.len().max(1);
+            }
+            crossterm::KeyCode::Char('n') if is_ctrl => {
+                self.selected = (self.selected + 1) % filtered.len().max(1);
+            }
+            crossterm::KeyCode::Up | crossterm::KeyCode::BackTab => {
+                self.selected = self
+                    .selected
+                    .checked_sub(1)
+                    .unwrap_or(filtered.len().saturating_sub(1));
+            }
+            crossterm::KeyCode::Char('p') if is_ctrl => {
+                self.selected = self
+                    .selected
+                    .checked_sub(1)
+                    .unwrap_or(filtered.len().saturating_sub(1));
+            }
+
+            crossterm::KeyCode::Enter => {
+                let command = filtered.into_iter().nth(self.selected)?;
+                return self.select(command);
+            }
+
+            // Edit the query, selecting the best match
+            _ => {
+                if let Some(request) = ui::helpers::input_request(key_event) {
+                    self.query.handle(request);
+                    self.selected = 0;
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl ui::components::Component for CommandPaletteComponent {
+    fn handle_key(
+        &mut self,
+        key_event: crossterm::KeyEvent,
+    ) -> Result<Option<domain::Action>> {
+        // Leave the key to the key map while closed
+        if !self.is_open {
+            return Ok(None);
+        }
+
+        let action = if self.prompting.is_some() {
+            self.handle_prompt_key(key_event)
+        } else {
+            self.handle_list_key(key_event)
+        };
+
+        // Consume every key while open, so nothing below the palette sees it
+        Ok(Some(action.unwrap_or(domain::Action::Nil)))
+    }
+
+    fn handle_mouse(
+        &mut self,
+        mouse_event: crossterm::MouseEvent,
+    ) -> Result<Option<domain::Action>> {
+        if !self.is_open {
+            return Ok(None);
+        }
+
+        // Scroll through the matching commands
+        let len = self.filtered().len().max(1);
+        match mouse_event.kind {
+            crossterm::MouseEventKind::ScrollDown => {
+                self.selected = (self.selected + 1).min(len - 1)
+            }
+            crossterm::MouseEventKind::ScrollUp => {
+                self.selected = self.selected.saturating_sub(1)
+            }
+            _ => {}
+        }
+
+        // Consume every mouse event while open, as with keys
+        Ok(Some(domain::Action::Nil))
+    }
+
+    fn update(&mut self, action: &domain::Action) -> Result<Option<domain::Action>> {
+        if *action == domain::Action::OpenCommandPalette {
+            self.open();
+        }
+
+        Ok(None)
+    }
+
+    fn render(&mut self, frame: &mut ratatui::Frame, area: layout::Rect) {
+        if !self.is_open {
+            return;
+        }
+
+        let area = ui::helpers::overlay(60, 60, area);
+        frame.render_widget(widgets::Clear, area);
+
+        let block = widgets::Block::bordered()
+            .title(" Command palette ")
+            .title_bottom(
+                text::Line::from(" ↑/↓ select | Enter run | Esc close ")
+                    .right_aligned(),
+            );
+        let inner_area = block.inner(area);
+        frame.render_widget(block, area);
+
+        let (input_area, list_area) = {
+            let split = layout::Layout::vertical([
+                layout::Constraint::Length(2),
+                layout::Constraint::Min(0),
+            ])
+            .split(inner_area);
+            (split[0], split[1])
+        };
+
+        //-- 1. Render the argument prompt, if a command is waiting for one
+        if let Some((command, input)) = &self.prompting {
+            let CommandKind::Prompt { prompt, .. } = command.kind else {
+                return;
+            };
+            let line = format!("{prompt}: {}", input.value());
+            frame.render_widget(widgets::Paragraph::new(line), input_area);
+            frame.set_cursor_position(layout::Position::new(
+                input_area.x + (prompt.len() + 2 + input.visual_cursor()) as u16,
+                input_area.y,
+            ));
+
+            let description = widgets::Paragraph::new(command.description.clone())
+                .style(self.theme.style("text.dimmed"));
+            frame.render_widget(description, list_area);
+            return;
+        }
+
+        //-- 2. Render the query input
+        let line = format!("> {}", self.query.value());
+        frame.render_widget(widgets::Paragraph::new(line), input_area);
+        frame.set_cursor_position(layout::Position::new(
+            input_area.x + 2 + self.query.visual_cursor() as u16,
+            input_area.y,
+        ));
+
+        //-- 3. Render the matching commands, highlighting the selected one
+        let rows = self.filtered().into_iter().map(|command| {
+            let name = if command.is_admin_only {
+                format!("{} (admin)", command.name)
+            } else {
+                command.name
+            };
+            widgets::Row::new([
+                text::Span::raw(name),
+                text::Span::styled(
+                    command.description,
+                    self.theme.style("text.dimmed"),
+                ),
+            ])
+        });
+        let widths = [layout::Constraint::Length(30), layout::Constraint::Fill(1)];
+        let table = widgets::Table::new(rows, widths).highlight_style(
+            self.theme.style("table.selected"),
+        );
+        let mut table_state =
+            widgets::TableState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, list_area, &mut table_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_score_ranks_word_starts_first() {
+        //-- Setup and Fixtures (Arrange)
+        let query = "rs";
+        let names: Vec<String> =
+            commands().into_iter().map(|command| command.name).collect();
+        let score = |name: &str| {
+            assert!(names.iter().any(|n| n == name), "no `{name}` command");
+            fuzzy_score(query, name)
+        };
+
+        //-- Execute Function (Act)
+        let revoke_sessions = score("Revoke sessions");
+        let clear_toast = score("Clear toast");
+        let home = score("Go to Home");
+
+        //-- Checks (Assertions)
+        assert!(revoke_sessions > clear_toast);
+        assert!(clear_toast.is_some());
+        assert_eq!(home, None);
+    }
+}
@@ -0,0 +1,198 @@
+//-- ./src/ui/components/statistics.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Statistics component
+//!
+//! Counts the tick actions and the frames to show the application tick and
+//! frame rates. Frames are counted as they are drawn, and as they are skipped
+//! by the render handler with nothing changed to draw, over the same window.
+//! ---
+
+use std::time;
+
+use ratatui::layout;
+
+use crate::{domain, prelude::*, ui};
+
+/// A rolling count of events per second
+#[derive(Debug, Clone, PartialEq)]
+struct RateCounter {
+    /// Time since the rate was last calculated
+    last_update: time::Instant,
+
+    /// The number of events since the rate was last calculated
+    count: u32,
+
+    /// Rolling events per second calculation
+    per_second: f64,
+}
+
+impl Default for RateCounter {
+    fn default() -> Self {
+        Self {
+            last_update: time::Instant::now(),
+            count: 0,
+            per_second: 0.0,
+        }
+    }
+}
+
+impl RateCounter {
+    /// Count an event, updating the rate once a second has elapsed
+    fn count(&mut self) {
+        // Increment the count by one
+        self.count += 1;
+
+        // Calculate elapsed time since the last update
+        let now = time::Instant::now();
+        let elapsed = (now - self.last_update).as_secs_f64();
+
+        // If elapsed time is grater than one second update the rate
+        if elapsed >= 1.0 {
+            self.per_second = self.count as f64 / elapsed;
+            self.last_update = now;
+            self.count = 0;
+        }
+    }
+}
+
+/// Rolling counts of the frames drawn and skipped per second, over the same
+/// window so the two rates add up to the render rate
+#[derive(Debug, Clone, PartialEq)]
+struct FrameCounter {
+    /// Time since the rates were last calculated
+    last_update: time::Instant,
+
+    /// The number of frames drawn since the rates were last calculated
+    drawn: u32,
+
+    /// The number of frames skipped since the rates were last calculated
+    skipped: u32,
+
+    /// Rolling frames drawn per second calculation
+    drawn_per_second: f64,
+
+    /// Rolling frames skipped per second calculation
+    skipped_per_second: f64,
+}
+
+impl Default for FrameCounter {
+    fn default() -> Self {
+        Self {
+            last_update: time::Instant::now(),
+            drawn: 0,
+            skipped: 0,
+            drawn_per_second: 0.0,
+            skipped_per_second: 0.0,
+        }
+    }
+}
+
+impl FrameCounter {
+    /// Count a frame drawn or skipped, updating the rates once a second has
+    /// elapsed
+    fn count(&mut self, is_drawn: bool) {
+        if is_drawn {
+            self.drawn += 1;
+        } else {
+            self.skipped += 1;
+        }
+
+        let now = time::Instant::now();
+        let elapsed = (now - self.last_update).as_secs_f64();
+        if elapsed >= 1.0 {
+            self.drawn_per_second = self.drawn as f64 / elapsed;
+            self.skipped_per_second = self.skipped as f64 / elapsed;
+            self.last_update = now;
+            self.drawn = 0;
+            self.skipped = 0;
+        }
+    }
+}
+
+/// Statistics component
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatisticsComponent {
+    /// Show the statistics, set in the config file
+    is_enabled: bool,
+
+    /// Tick rate counter
+    ticks: RateCounter,
+
+    /// Frame rate counter, of the frames drawn and skipped
+    frames: FrameCounter,
+
+    /// Named styles to render with
+    theme: ui::Theme,
+}
+
+impl StatisticsComponent {
+    /// Construct a new statistics component
+    pub fn new(is_enabled: bool, theme: ui::Theme) -> Self {
+        Self {
+            is_enabled,
+            theme,
+            ticks: RateCounter::default(),
+            frames: FrameCounter::default(),
+        }
+    }
+
+    /// Count a frame the render handler skipped, with nothing changed to draw
+    pub fn skip_frame(&mut self) {
+        self.frames.count(false);
+    }
+}
+
+impl ui::components::Component for StatisticsComponent {
+    fn update(&mut self, action: &domain::Action) -> Result<Option<domain::Action>> {
+        if *action == domain::Action::Tick {
+            self.ticks.count();
+        }
+
+        Ok(None)
+    }
+
+    fn render(&mut self, frame: &mut ratatui::Frame, area: layout::Rect) {
+        // Only called when a frame is drawn
+        self.frames.count(true);
+        if !self.is_enabled {
+            return;
+        }
+
+        let statistics_widget = ui::custom_widgets::StatisticsWidget::update(
+            self.ticks.per_second,
+            self.frames.drawn_per_second,
+            self.frames.skipped_per_second,
+        )
+        .style(self.theme.style("statistics"));
+        frame.render_widget(statistics_widget, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn drawn_and_skipped_frames_share_a_window() {
+        //-- Setup and Fixtures (Arrange)
+        let mut frames = FrameCounter::default();
+        for is_drawn in [true, false, false, true] {
+            frames.count(is_drawn);
+        }
+        frames.last_update -= time::Duration::from_secs(1);
+
+        //-- Execute Function (Act)
+        frames.count(false);
+
+        //-- Checks (Assertions)
+        // Three of the five frames were skipped, over just over a second
+        let total = frames.drawn_per_second + frames.skipped_per_second;
+        assert!(frames.skipped_per_second > frames.drawn_per_second);
+        assert!(frames.drawn_per_second > 0.0);
+        assert!((frames.skipped_per_second / total - 0.6).abs() < 1e-9);
+        assert_eq!((frames.drawn, frames.skipped), (0, 0));
+    }
+}
@@ -0,0 +1,171 @@
+//-- ./src/ui/components/toast.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Toast component
+//!
+//! Queues toast messages and stacks them in the top right corner, newest
+//! first. Each toast kind is shown for its configured duration, duplicate
+//! messages are merged with a count, and a toast may offer a follow up
+//! action, i.e. Retry, triggered by a key.
+//! ---
+
+use crossterm::event as crossterm;
+use ratatui::layout;
+
+use crate::{config, domain, prelude::*, state, ui};
+
+/// Height of each stacked toast
+const TOAST_HEIGHT: u16 = 4;
+
+/// Toast component, owning the toast message queue
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ToastComponent {
+    /// Toast message queue and the toasts being shown
+    state: state::ToastState,
+
+    /// Toast durations and how many are stacked at once
+    config: config::ToastConfig,
+
+    /// The key bound to the toast follow up action, shown on the toast
+    follow_up_key: Option<String>,
+
+    /// Where each visible toast was last rendered, for mouse clicks
+    areas: Vec<layout::Rect>,
+
+    /// Named styles to render with
+    theme: ui::Theme,
+}
+
+impl ToastComponent {
+    /// Construct the toast component with an empty queue
+    pub fn new(
+        theme: ui::Theme,
+        config: config::ToastConfig,
+        follow_up_key: Option<String>,
+    ) -> Self {
+        Self {
+            config,
+            follow_up_key,
+            theme,
+            ..Self::default()
+        }
+    }
+
+    /// Take the follow up action of the newest toast offering one, asking for
+    /// confirmation first if it is destructive, as a sticky error toast can be
+    /// followed up long after it was raised
+    fn follow_up(&mut self) -> Option<domain::Action> {
+        let index = self
+            .state
+            .visible
+            .iter()
+            .position(|toast| toast.follow_up.is_some())?;
+        let toast = self.state.dismiss(index)?;
+
+        toast.follow_up.map(|(_, action)| action.confirmed())
+    }
+}
+
+impl ui::components::Component for ToastComponent {
+    fn handle_mouse(
+        &mut self,
+        mouse_event: crossterm::MouseEvent,
+    ) -> Result<Option<domain::Action>> {
+        // Dismiss the toast when clicked
+        let is_click = mouse_event.kind
+            == crossterm::MouseEventKind::Down(crossterm::MouseButton::Left);
+        let position = layout::Position::new(mouse_event.column, mouse_event.row);
+        let clicked = self.areas.iter().position(|area| area.contains(position));
+        match clicked {
+            Some(index) if is_click => {
+                self.state.dismiss(index);
+                Ok(Some(domain::Action::Nil))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn update(&mut self, action: &domain::Action) -> Result<Option<domain::Action>> {
+        match action {
+            // Add toast message to the queue, or merge it with a duplicate
+            domain::Action::Toast(t) => self.state.push(t.clone()),
+
+            // Dismiss the newest toast message
+            domain::Action::ClearToast => {
+                self.state.dismiss(0);
+            }
+
+            // Trigger the follow up action of the newest toast offering one
+            domain::Action::ToastFollowUp => return Ok(self.follow_up()),
+
+            // Expire and show toast messages
+            domain::Action::Tick => {
+                let config = &self.config;
+                self.state
+                    .expire(|kind| config.duration(kind), config.max_visible);
+            }
+
+            _ => {}
+        }
+
+        Ok(None)
+    }
+
+    fn render(&mut self, frame: &mut ratatui::Frame, area: layout::Rect) {
+        self.areas.clear();
+
+        // Span narrow terminals, and give longer messages room on wide ones
+        let width =
+            ui::Breakpoint::of(area).pick(area.width.saturating_sub(2), 40, 56);
+
+        // Stack the toasts down from the top right corner, while they fit
+        let mut toast_area = ui::helpers::top_right(width, TOAST_HEIGHT, area);
+        for toast in &self.state.visible {
+            if toast_area.bottom() > area.bottom() {
+                break;
+            }
+
+            let toast_widget =
+                ui::custom_widgets::ToastWidget::init(toast.clone(), &self.theme)
+                    .follow_up_key(self.follow_up_key.clone());
+            frame.render_widget(toast_widget, toast_area);
+            self.areas.push(toast_area);
+
+            toast_area.y += TOAST_HEIGHT;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+    use ui::components::Component;
+
+    #[test]
+    fn destructive_follow_ups_ask_for_confirmation() {
+        //-- Setup and Fixtures (Arrange)
+        let mut component = ToastComponent::default();
+        let revoke = domain::Action::RevokeSessions("user@example.com".into());
+        let toast = domain::Toast::new("Unable to revoke sessions")
+            .kind(domain::ToastKind::Error)
+            .follow_up("Retry", revoke.clone());
+        component.update(&domain::Action::Toast(toast)).unwrap();
+        component.update(&domain::Action::Tick).unwrap();
+
+        //-- Execute Function (Act)
+        let follow_up = component.update(&domain::Action::ToastFollowUp).unwrap();
+
+        //-- Checks (Assertions)
+        let Some(domain::Action::Dialog(dialog)) = follow_up else {
+            panic!("expected a confirm dialog, got {follow_up:?}");
+        };
+        assert_eq!(
+            dialog.kind,
+            domain::DialogKind::Confirm {
+                on_yes: Box::new(revoke)
+            }
+        );
+    }
+}
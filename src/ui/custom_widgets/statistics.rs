@@ -17,16 +17,30 @@ use ratatui::{
 pub struct StatisticsWidget {
     pub ticks_per_second: f64,
     pub frames_per_second: f64,
+    pub skipped_per_second: f64,
+    pub style: Style,
 }
 
 impl StatisticsWidget {
     /// Update the application statistics to show
-    pub fn update(ticks_per_second: f64, frames_per_second: f64) -> Self {
+    pub fn update(
+        ticks_per_second: f64,
+        frames_per_second: f64,
+        skipped_per_second: f64,
+    ) -> Self {
         Self {
             ticks_per_second,
             frames_per_second,
+            skipped_per_second,
+            style: Style::new().dim(),
         }
     }
+
+    /// Set the style of the statistics text
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
 }
 
 impl Widget for StatisticsWidget {
@@ -38,12 +52,12 @@ impl Widget for StatisticsWidget {
 
         // Construct fps message
         let message = format!(
-            "{:.2} ticks/sec, {:.2} frames/sec",
-            self.ticks_per_second, self.frames_per_second
+            "{:.2} ticks/sec, {:.2} frames/sec, {:.2} skipped/sec",
+            self.ticks_per_second, self.frames_per_second, self.skipped_per_second
         );
 
         // Construct the text span
-        let span = Span::styled(message, Style::new().dim());
+        let span = Span::styled(message, self.style);
 
         // Construct the paragraph that holds the span
         let paragraph = Paragraph::new(span).right_aligned();
@@ -7,55 +7,50 @@
 
 use ratatui::{layout, style, widgets};
 
-use crate::domain;
+use crate::{domain, ui};
 
 /// Footer status widget
 #[derive(Debug, PartialEq, Default)]
 pub struct StatusWidget {
     backend_status: domain::BackendStatus,
+    style: style::Style,
 }
 
 impl StatusWidget {
-    /// Initiate a new status widget
-    pub fn init(backend_status: domain::BackendStatus) -> Self {
-        Self { backend_status }
+    /// Initiate a new status widget, styled for the status by the theme
+    pub fn init(backend_status: domain::BackendStatus, theme: &ui::Theme) -> Self {
+        let style = match backend_status {
+            domain::BackendStatus::Connecting => theme.style("status.connecting"),
+            domain::BackendStatus::Offline => theme.style("status.offline"),
+            domain::BackendStatus::Online => theme.style("status.online"),
+            domain::BackendStatus::LoggedIn => theme.style("status.logged_in"),
+            domain::BackendStatus::Degraded => theme.style("status.degraded"),
+            domain::BackendStatus::Unauthorized => {
+                theme.style("status.unauthorized")
+            }
+            domain::BackendStatus::Incompatible => {
+                theme.style("status.incompatible")
+            }
+        };
+
+        Self {
+            backend_status,
+            style,
+        }
     }
 }
 
 impl widgets::Widget for StatusWidget {
     /// [Required] Render the custom widget using the assigned area and terminal buffer
     fn render(self, area: layout::Rect, buf: &mut ratatui::buffer::Buffer) {
-        // Set backend server status and style
-        let (status, style) = match self.backend_status {
-            domain::BackendStatus::Offline => {
-                let status = "Offline";
-                let style = style::Style::default()
-                    .fg(style::Color::LightRed)
-                    // .bg(style::Color::Green)
-                    .add_modifier(style::Modifier::BOLD);
-                (status, style)
-            }
-            domain::BackendStatus::Online => {
-                let status = "Online";
-                let style = style::Style::default()
-                    .fg(style::Color::LightGreen)
-                    .add_modifier(style::Modifier::BOLD);
-                (status, style)
-            }
-            domain::BackendStatus::LoggedIn => {
-                let status = "Logged In";
-                let style = style::Style::default()
-                    .fg(style::Color::Green)
-                    .add_modifier(style::Modifier::BOLD);
-                (status, style)
-            }
-        };
+        // Set backend server status
+        let status = self.backend_status.to_string();
 
         // Construct a new block widget
         let block = widgets::Block::new();
 
         // Construct a new paragraph
-        let paragraph = widgets::Paragraph::new(status).centered().style(style);
+        let paragraph = widgets::Paragraph::new(status).centered().style(self.style);
 
         // Render paragraph in the block
         paragraph.block(block).render(area, buf);
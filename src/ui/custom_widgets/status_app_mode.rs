@@ -1,47 +1,40 @@
 use ratatui::{layout, style, widgets};
 
-use crate::domain;
+use crate::{domain, ui};
 
 /// Footer status widget
 #[derive(Debug, PartialEq, Default)]
 pub struct StatusAppModeWidget {
     app_mode: domain::AppMode,
+    style: style::Style,
 }
 
 impl StatusAppModeWidget {
-    /// Initiate a new status widget
-    pub fn init(app_mode: domain::AppMode) -> Self {
-        Self { app_mode }
+    /// Initiate a new status widget, styled for the mode by the theme
+    pub fn init(app_mode: domain::AppMode, theme: &ui::Theme) -> Self {
+        let style = match app_mode {
+            domain::AppMode::Normal => theme.style("mode.normal"),
+            domain::AppMode::Input => theme.style("mode.input"),
+        };
+
+        Self { app_mode, style }
     }
 }
 
 impl widgets::Widget for StatusAppModeWidget {
     /// [Required] Render the custom widget using the assigned area and terminal buffer
     fn render(self, area: layout::Rect, buf: &mut ratatui::buffer::Buffer) {
-        // Set backend server status and style
-        let (status, style) = match self.app_mode {
-            domain::AppMode::Normal => {
-                let status = "Normal";
-                let style = style::Style::default()
-                    .fg(style::Color::White)
-                    // .bg(style::Color::Green)
-                    .add_modifier(style::Modifier::BOLD);
-                (status, style)
-            }
-            domain::AppMode::Input => {
-                let status = "::Input::";
-                let style = style::Style::default()
-                    .fg(style::Color::LightGreen)
-                    .add_modifier(style::Modifier::BOLD);
-                (status, style)
-            }
+        // Set application mode status
+        let status = match self.app_mode {
+            domain::AppMode::Normal => "Normal",
+            domain::AppMode::Input => "::Input::",
         };
 
         // Construct a new block widget
         let block = widgets::Block::new();
 
         // Construct a new paragraph
-        let paragraph = widgets::Paragraph::new(status).left_aligned().style(style);
+        let paragraph = widgets::Paragraph::new(status).left_aligned().style(self.style);
 
         // Render paragraph in the block
         paragraph.block(block).render(area, buf);
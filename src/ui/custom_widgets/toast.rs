@@ -11,74 +11,69 @@ use crate::{domain, ui};
 
 pub struct ToastWidget {
     pub(crate) toast: domain::Toast,
+    style: style::Style,
+    follow_up_key: Option<String>,
 }
 
 impl ToastWidget {
-    pub fn init(toast: domain::Toast) -> Self {
+    /// Initiate a new toast widget, styled for the toast kind by the theme
+    pub fn init(toast: domain::Toast, theme: &ui::Theme) -> Self {
+        let style = theme.toast(&toast.kind);
+
         Self {
-            toast
+            toast,
+            style,
+            follow_up_key: None,
         }
     }
+
+    /// Name the key that triggers the toast follow up, shown with its label
+    pub fn follow_up_key(mut self, key: Option<String>) -> Self {
+        self.follow_up_key = key;
+        self
+    }
 }
 
 impl widgets::Widget for ToastWidget {
     /// [Required] Render the custom widget using the assigned area and terminal buffer
     fn render(self, area: layout::Rect, buf: &mut ratatui::buffer::Buffer) {
-        // Calculate widget layout area / position
-        let top_right = ui::helpers::top_right(40, 4, area);
-
-        // Set the toast style based on the toast kind
-        let (toast_title, toast_style) = match self.toast.kind {
-            domain::ToastKind::Error => {
-                let toast_style = style::Style::default()
-                    .fg(style::Color::Red)
-                    .add_modifier(style::Modifier::BOLD);
-                let toast_title = text::Span::styled("Error", toast_style);
-                (toast_title, toast_style)
-            }
-            domain::ToastKind::Info => {
-                let toast_style = style::Style::default()
-                    .fg(style::Color::LightBlue)
-                    .add_modifier(style::Modifier::BOLD);
-                let toast_title = text::Span::styled("Info", toast_style);
-                (toast_title, toast_style)
-            }
-            domain::ToastKind::Notification => {
-                let toast_style = style::Style::default()
-                    .fg(style::Color::White)
-                    .add_modifier(style::Modifier::BOLD);
-                let toast_title = text::Span::styled("Notification", toast_style);
-                (toast_title, toast_style)
-            }
-            domain::ToastKind::Success => {
-                let toast_style = style::Style::default()
-                    .fg(style::Color::LightGreen)
-                    .add_modifier(style::Modifier::BOLD);
-                let toast_title = text::Span::styled("Success", toast_style);
-                (toast_title, toast_style)
-            }
-            domain::ToastKind::Warning => {
-                let toast_style = style::Style::default()
-                    .fg(style::Color::LightRed)
-                    .add_modifier(style::Modifier::BOLD);
-                let toast_title = text::Span::styled("Warning", toast_style);
-                (toast_title, toast_style)
-            }
+        // Title the toast with its kind
+        let toast_title = match self.toast.kind {
+            domain::ToastKind::Error => "Error",
+            domain::ToastKind::Info => "Info",
+            domain::ToastKind::Notification => "Notification",
+            domain::ToastKind::Success => "Success",
+            domain::ToastKind::Warning => "Warning",
         };
+        // Count the merged duplicates in the title
+        let toast_title = if self.toast.count > 1 {
+            format!("{toast_title} (x{})", self.toast.count)
+        } else {
+            toast_title.to_string()
+        };
+        let toast_title = text::Span::styled(toast_title, self.style);
 
         // Setup widget block
         let block = widgets::Block::bordered()
             .title(toast_title)
-            .style(toast_style);
+            .style(self.style);
+
+        // Offer the follow up action, i.e. Retry, with the key to trigger it
+        let block = match (&self.toast.follow_up, &self.follow_up_key) {
+            (Some((label, _)), Some(key)) => block.title_bottom(
+                text::Line::from(format!(" {label}: {key} ")).right_aligned(),
+            ),
+            _ => block,
+        };
 
         // Define the paragraph to be rendered
         let paragraph = widgets::Paragraph::new(self.toast.message)
             .wrap(widgets::Wrap { trim: true });
 
         // Clear/reset a certain area to allow overdrawing of toast.
-        widgets::Clear.render(top_right, buf);
+        widgets::Clear.render(area, buf);
 
         // Render the widget block
-        paragraph.block(block).render(top_right, buf);
+        paragraph.block(block).render(area, buf);
     }
 }
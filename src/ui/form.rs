@@ -0,0 +1,124 @@
+//-- ./src/ui/form.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Form ui layout
+//!
+//! Contains the layout code for rendering a form of text input fields in a
+//! centred box
+//! ---
+
+use ratatui::{layout, style, text, widgets};
+
+use crate::{state::form::FormState, ui};
+
+/// Width of the form box
+const FORM_WIDTH: u16 = 60;
+
+/// Height of each field, a bordered input and an error line
+const FIELD_HEIGHT: u16 = 4;
+
+/// Render a form in the centre of the area
+///
+/// # Parameters
+///
+/// * `form` - The form state to render
+/// * `title` - Title of the form box
+/// * `help` - Key help shown at the bottom of the form box
+/// * `is_editing` - Is the form taking input, highlighting the field in focus
+/// * `theme` - Named styles to render with
+pub fn render(
+    form: &FormState,
+    title: &str,
+    help: &str,
+    is_editing: bool,
+    theme: &ui::Theme,
+    area: layout::Rect,
+    frame: &mut ratatui::Frame,
+) {
+    //-- 1. Calculate the form box area
+    let fields_height = form.fields.len() as u16 * FIELD_HEIGHT;
+    let height = fields_height + 4; // Borders, notice and help lines
+    let [form_area] = layout::Layout::vertical([layout::Constraint::Length(height)])
+        .flex(layout::Flex::Center)
+        .areas(area);
+    let [form_area] =
+        layout::Layout::horizontal([layout::Constraint::Length(FORM_WIDTH)])
+            .flex(layout::Flex::Center)
+            .areas(form_area);
+
+    let block = widgets::Block::bordered()
+        .title(format!(" {title} "))
+        .title_bottom(text::Line::from(format!(" {help} ")).centered());
+    let inner_area = block.inner(form_area);
+    frame.render_widget(block, form_area);
+
+    //-- 2. Render the notice line
+    let [notice_area, fields_area] = layout::Layout::vertical([
+        layout::Constraint::Length(1),
+        layout::Constraint::Fill(1),
+    ])
+    .areas(inner_area);
+
+    if let Some(notice) = &form.notice {
+        let notice = widgets::Paragraph::new(notice.as_str())
+            .centered()
+            .style(style::Style::default().add_modifier(style::Modifier::BOLD));
+        frame.render_widget(notice, notice_area);
+    }
+
+    //-- 3. Render each field with its error line
+    let field_areas = layout::Layout::vertical(
+        form.fields
+            .iter()
+            .map(|_| layout::Constraint::Length(FIELD_HEIGHT)),
+    )
+    .split(fields_area);
+
+    for (index, (field, field_area)) in
+        form.fields.iter().zip(field_areas.iter()).enumerate()
+    {
+        let is_focused = is_editing && index == form.focus;
+
+        let [input_area, error_area] = layout::Layout::vertical([
+            layout::Constraint::Length(3),
+            layout::Constraint::Length(1),
+        ])
+        .areas(*field_area);
+
+        // Scroll the input value to keep the cursor in view
+        let input_width = input_area.width.saturating_sub(2) as usize;
+        let scroll = field.input.visual_scroll(input_width);
+        let value = if field.is_secret {
+            "*".repeat(field.input.value().chars().count())
+        } else {
+            field.input.value().to_string()
+        };
+
+        let border_style = if is_focused {
+            theme.style("form.focus")
+        } else {
+            style::Style::default()
+        };
+        let input = widgets::Paragraph::new(value)
+            .scroll((0, scroll as u16))
+            .block(
+                widgets::Block::bordered()
+                    .title(field.label)
+                    .border_style(border_style),
+            );
+        frame.render_widget(input, input_area);
+
+        // Place the terminal cursor in the field in focus
+        if is_focused {
+            let cursor = (field.input.visual_cursor().max(scroll) - scroll) as u16;
+            frame.set_cursor_position((input_area.x + 1 + cursor, input_area.y + 1));
+        }
+
+        if let Some(error) = &field.error {
+            let error = widgets::Paragraph::new(error.as_str())
+                .style(theme.style("form.error"));
+            frame.render_widget(error, error_area);
+        }
+    }
+}
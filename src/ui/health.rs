@@ -0,0 +1,96 @@
+//-- ./src/ui/health.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Backend health panel ui layout
+//!
+//! Contains the layout code for rendering the backend status, what it means,
+//! since when and the last error seen, then the ping round trip times, as min,
+//! average and p95 statistics over a sparkline of the latest pings
+//! ---
+
+use std::time;
+
+use ratatui::{layout, text, widgets};
+
+use crate::{config, state, ui};
+
+pub fn render(
+    state: &state::State,
+    config: &config::BackendConfig,
+    theme: &ui::Theme,
+    area: layout::Rect,
+    frame: &mut ratatui::Frame,
+) {
+    let latency = &state.backend.latency;
+    let threshold = config.degraded_latency();
+
+    // Flag a slow backend in the title
+    let title = if latency.is_degraded(threshold) {
+        text::Line::from(vec![
+            text::Span::raw(" Backend health: "),
+            text::Span::styled("Degraded", theme.style("toast.warning")),
+            text::Span::raw(" "),
+        ])
+    } else {
+        text::Line::from(format!(" Backend health: {} ", state.backend.status))
+    };
+    let block = widgets::Block::bordered().title(title);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let (status_area, error_area, statistics_area, sparkline_area) = {
+        let split = layout::Layout::vertical([
+            layout::Constraint::Length(1), // Status
+            layout::Constraint::Length(1), // Last error
+            layout::Constraint::Length(1), // Statistics
+            layout::Constraint::Fill(1),   // Sparkline
+        ])
+        .split(inner);
+        (split[0], split[1], split[2], split[3])
+    };
+
+    let backend = &state.backend;
+    let status = format!(
+        "{} since {}",
+        backend.status.description(),
+        backend.status_since.format("%H:%M:%S"),
+    );
+    frame.render_widget(widgets::Paragraph::new(status), status_area);
+
+    let error = match (&backend.last_error, backend.last_error_at) {
+        (Some(error), Some(at)) => text::Span::styled(
+            format!("Last error at {}: {error}", at.format("%H:%M:%S")),
+            theme.style("toast.error"),
+        ),
+        _ => text::Span::styled("No errors", theme.style("text.dimmed")),
+    };
+    frame.render_widget(widgets::Paragraph::new(error), error_area);
+
+    let statistics = format!(
+        "Last {}  Min {}  Avg {}  p95 {}  Degraded over {}",
+        millis(latency.last()),
+        millis(latency.min()),
+        millis(latency.average()),
+        millis(latency.p95()),
+        millis(Some(threshold)),
+    );
+    frame.render_widget(widgets::Paragraph::new(statistics), statistics_area);
+
+    // Show the latest pings that fit, scaled to the slowest of them
+    let samples = latency.millis();
+    let first = samples.len().saturating_sub(sparkline_area.width as usize);
+    let visible = &samples[first..];
+    let sparkline = widgets::Sparkline::default()
+        .data(visible)
+        .style(theme.style("statistics"));
+    frame.render_widget(sparkline, sparkline_area);
+}
+
+/// Format a round trip time in milliseconds, `-` before the first ping
+fn millis(duration: Option<time::Duration>) -> String {
+    match duration {
+        Some(duration) => format!("{}ms", duration.as_millis()),
+        None => "-".to_string(),
+    }
+}
@@ -5,7 +5,11 @@
 //! Collection of common ui helper functions
 //! ---
 
-use ratatui::layout;
+use crossterm::event as crossterm;
+use ratatui::{layout, widgets};
+use tui_input::InputRequest;
+
+use crate::{state, ui};
 
 /// Position the widget at the top right corner
 pub fn top_right(width: u16, height: u16, area: layout::Rect) -> layout::Rect {
@@ -24,9 +28,10 @@ pub fn top_right(width: u16, height: u16, area: layout::Rect) -> layout::Rect {
     .split(toast_layout[1])[1]
 }
 
-/// Center rect using up certain percentage of the available rect `r`
+/// Center a rect of the height vertically, taking the whole area if the area
+/// is not tall enough
 pub fn centered_vert(height: u16, area: layout::Rect) -> layout::Rect {
-    let spacing = (area.height - height) / 2;
+    let spacing = area.height.saturating_sub(height) / 2;
     layout::Layout::vertical([
         layout::Constraint::Length(spacing),
         layout::Constraint::Length(height),
@@ -56,6 +61,24 @@ pub fn center_center(
     .split(popup_layout[1])[1]
 }
 
+/// Center a rect of the width and height, clamped to the area
+pub fn centered(width: u16, height: u16, area: layout::Rect) -> layout::Rect {
+    let [area] = layout::Layout::horizontal([layout::Constraint::Length(width)])
+        .flex(layout::Flex::Center)
+        .areas(area);
+    let [area] = layout::Layout::vertical([layout::Constraint::Length(height)])
+        .flex(layout::Flex::Center)
+        .areas(area);
+    area
+}
+
+/// Center an overlay using a percentage of the area, widening it to the whole
+/// width on narrow terminals
+pub fn overlay(percent_x: u16, percent_y: u16, area: layout::Rect) -> layout::Rect {
+    let percent_x = ui::Breakpoint::of(area).pick(100, percent_x, percent_x);
+    center_center(percent_x, percent_y, area)
+}
+
 pub fn body(area: layout::Rect) -> layout::Rect {
     let (body_area, footer_area) = {
         let split = layout::Layout::vertical([
@@ -80,4 +103,62 @@ pub fn footer(area: layout::Rect) -> layout::Rect {
     };
     
     footer_area
-}
\ No newline at end of file
+}
+/// Keep the cells of the table columns shown, by column index
+pub fn pick_columns<T>(
+    cells: impl IntoIterator<Item = T>,
+    columns: &[usize],
+) -> Vec<T> {
+    cells
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| columns.contains(index))
+        .map(|(_, cell)| cell)
+        .collect()
+}
+
+/// Map a key event to a text input request
+pub fn input_request(key_event: crossterm::KeyEvent) -> Option<InputRequest> {
+    let is_ctrl = key_event
+        .modifiers
+        .contains(crossterm::KeyModifiers::CONTROL);
+
+    match key_event.code {
+        crossterm::KeyCode::Char('u') if is_ctrl => Some(InputRequest::DeleteLine),
+        crossterm::KeyCode::Char('w') if is_ctrl => {
+            Some(InputRequest::DeletePrevWord)
+        }
+        crossterm::KeyCode::Char(_) if is_ctrl => None,
+        crossterm::KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
+        crossterm::KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
+        crossterm::KeyCode::Delete => Some(InputRequest::DeleteNextChar),
+        crossterm::KeyCode::Left => Some(InputRequest::GoToPrevChar),
+        crossterm::KeyCode::Right => Some(InputRequest::GoToNextChar),
+        crossterm::KeyCode::Home => Some(InputRequest::GoToStart),
+        crossterm::KeyCode::End => Some(InputRequest::GoToEnd),
+        _ => None,
+    }
+}
+
+/// Register the visible rows of a bordered table with a one line header as
+/// clickable, once the table has been rendered and its offset is known
+pub fn register_table_rows(
+    mouse: &mut state::MouseState,
+    area: layout::Rect,
+    table: &widgets::TableState,
+    len: usize,
+) {
+    let rows_area = widgets::Block::bordered().inner(area);
+    let first_row_y = rows_area.y + 1;
+    let visible_rows = rows_area.height.saturating_sub(1) as usize;
+
+    for (line, row) in (table.offset()..len).take(visible_rows).enumerate() {
+        let row_area = layout::Rect::new(
+            rows_area.x,
+            first_row_y + line as u16,
+            rows_area.width,
+            1,
+        );
+        mouse.register(row_area, state::MouseTarget::TableRow(row));
+    }
+}
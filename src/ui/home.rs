@@ -0,0 +1,53 @@
+//-- ./src/ui/home.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Home ui layout
+//!
+//! Contains the layout code for rendering the landing screen once logged in,
+//! above the backend health panel
+//! ---
+
+use ratatui::{layout, text, widgets};
+
+use crate::{config, state, ui};
+
+pub fn render(
+    state: &state::State,
+    config: &config::BackendConfig,
+    theme: &ui::Theme,
+    area: layout::Rect,
+    frame: &mut ratatui::Frame,
+) {
+    let (home_area, health_area) = {
+        let split = layout::Layout::vertical([
+            layout::Constraint::Length(6), // Home
+            layout::Constraint::Min(6),    // Backend health
+        ])
+        .split(area);
+        (split[0], split[1])
+    };
+
+    let operator = state
+        .backend
+        .operator_email
+        .clone()
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let lines = vec![
+        text::Line::from(format!("Logged in as: {operator}")),
+        text::Line::from(format!("Role: {}", state.backend.role)),
+        text::Line::from(format!("Backend: {}", state.backend.status)),
+        text::Line::from(format!(
+            "Security events: {}",
+            state.security.events.len()
+        )),
+    ];
+
+    let paragraph = widgets::Paragraph::new(lines)
+        .block(widgets::Block::bordered().title(" Home "));
+
+    frame.render_widget(paragraph, home_area);
+
+    ui::health::render(state, config, theme, health_area, frame);
+}
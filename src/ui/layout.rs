@@ -9,50 +9,114 @@ use ratatui::{layout, widgets};
 
 use crate::{domain, state, Config, ui};
 
-use super::custom_widgets;
+use super::components::{self, Component};
 
-pub fn render(config: Config, state: &mut state::State, frame: &mut ratatui::Frame) {
+pub fn render(
+    config: Config,
+    theme: &ui::Theme,
+    state: &mut state::State,
+    components: &mut components::Components,
+    frame: &mut ratatui::Frame,
+) {
     // Get the terminal window area
     let terminal_area = frame.area();
 
-    // Split the terminal window into a body and a footer rectangle
-    let (body_area, footer_area) = {
+    // Clickable areas are registered again as the frame is rendered
+    state.mouse.clear();
+
+    // Ask for a bigger terminal rather than squeezing the layout
+    if ui::breakpoint::is_too_small(terminal_area) {
+        render_too_small(terminal_area, frame);
+        return;
+    }
+
+    // Split the terminal window into a tab bar, body and a footer rectangle
+    let (tabs_area, body_area, footer_area) = {
         let split = layout::Layout::vertical([
+            layout::Constraint::Length(1), // tabs
             layout::Constraint::Min(6),    // body
             layout::Constraint::Length(1), //footer
         ])
         .split(terminal_area);
-        (split[0], split[1])
+        (split[0], split[1], split[2])
     };
 
-    // Render the body rectangle
-    frame.render_widget(
-        widgets::Paragraph::new("Top")
-            .block(widgets::Block::new().borders(widgets::Borders::ALL)),
-        body_area,
-    );
+    //-- 1. Render the tab bar and the current screen in the body
+    ui::tabs::render(state, theme, tabs_area, frame);
+    render_screen(&config, state, theme, body_area, frame);
 
     //-- 2. Render the footer
-    ui::footer::render(config.clone(), state, footer_area, frame);
+    components.footer.render(frame, footer_area);
+
+    //-- 3. Render statistics, if set in the config file. I need to go last as
+    // I use the terminal area
+    components.statistics.render(frame, terminal_area);
+
+    //-- 4. Render toast message if there is a current one
+    components.toast.render(frame, terminal_area);
+
+    //-- 5. Render the notification history over the toasts, if open
+    components.notifications.render(frame, terminal_area);
 
-    //-- 3. Render statistics widget if set in the config file
-    // Construct the custom statistics widget
-    let statistics_widget = custom_widgets::StatisticsWidget::update(
-        state.app.ticks_per_second,
-        state.app.frames_per_second,
+    //-- 6. Render the command palette over everything else, if open
+    components.palette.render(frame, terminal_area);
+
+    //-- 7. Render the key binding help over everything else, if open
+    components.help.render(frame, terminal_area);
+
+    //-- 8. Render the modal dialogs above every other layer
+    components.modal.render(frame, terminal_area);
+}
+
+/// Tell the operator the terminal is too small for the layout
+fn render_too_small(area: layout::Rect, frame: &mut ratatui::Frame) {
+    let message = format!(
+        "Terminal too small: {}x{}, needs at least {}x{}",
+        area.width,
+        area.height,
+        ui::breakpoint::MIN_WIDTH,
+        ui::breakpoint::MIN_HEIGHT
     );
+    let paragraph = widgets::Paragraph::new(message)
+        .centered()
+        .wrap(widgets::Wrap { trim: true });
+    frame.render_widget(paragraph, ui::helpers::centered_vert(2, area));
+}
 
-    // Render the statistics widget, if config is set true. I need to go last as 
-    // I use the terminal area
-    if config.app.show_statistics {
-        frame.render_widget(statistics_widget, terminal_area);
+/// Route the current screen to its layout
+fn render_screen(
+    config: &Config,
+    state: &mut state::State,
+    theme: &ui::Theme,
+    area: layout::Rect, frame: &mut ratatui::Frame) {
+    let screen = state.navigation.current;
+
+    // Guard screens the operator role can not view
+    let is_permitted = state
+        .backend
+        .role
+        .is_permitted(&domain::Action::Navigate(screen));
+    if !is_permitted {
+        let message = format!("{screen} requires the {} role", domain::UserRole::Admin);
+        frame.render_widget(
+            widgets::Paragraph::new(message).block(widgets::Block::bordered()),
+            area,
+        );
+        return;
     }
 
-    //-- 4. Render toast message widget if there is a current one
-    if let Some(ref mut current_toast) = state.toast.current {
-        let toast_widget = custom_widgets::ToastWidget {
-            toast: current_toast.clone(),
-        };
-        frame.render_widget(toast_widget, terminal_area)
+    match screen {
+        domain::Screen::Login => ui::login::render(state, theme, area, frame),
+        domain::Screen::Register => ui::register::render(state, theme, area, frame),
+        domain::Screen::ForgotPassword | domain::Screen::ResetPassword => {
+            ui::reset_password::render(state, theme, area, frame)
+        }
+        domain::Screen::Home => {
+            ui::home::render(state, &config.backend, theme, area, frame)
+        }
+        domain::Screen::Logins => ui::logins::render(state, theme, area, frame),
+        domain::Screen::Security => ui::security::render(state, theme, area, frame),
+        domain::Screen::AuditLog => ui::audit::render(state, theme, area, frame),
+        domain::Screen::About => ui::about::render(theme, area, frame),
     }
 }
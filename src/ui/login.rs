@@ -0,0 +1,37 @@
+//-- ./src/ui/login.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Login ui layout
+//!
+//! Contains the layout code for rendering the login form
+//! ---
+
+use ratatui::layout;
+
+use crate::{domain, state, ui};
+
+pub fn render(
+    state: &state::State,
+    theme: &ui::Theme,
+    area: layout::Rect,
+    frame: &mut ratatui::Frame,
+) {
+    let is_editing = state.app.mode == domain::AppMode::Input;
+
+    let help = if is_editing {
+        "Enter login | Tab next field | Esc done"
+    } else {
+        "m edit | r register | f forgot password"
+    };
+
+    ui::form::render(
+        &state.auth.login,
+        "Login",
+        help,
+        is_editing,
+        theme,
+        area,
+        frame,
+    );
+}
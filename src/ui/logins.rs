@@ -0,0 +1,138 @@
+//-- ./src/ui/logins.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Logins ui layout
+//!
+//! Contains the layout code for rendering the most recent logins, beside the
+//! detail of the selected login: the IP addresses known for its user, whether
+//! it raised a security event and the user's other recent logins
+//! ---
+
+use ratatui::{layout, text, widgets};
+
+use crate::{domain, state, ui};
+
+pub fn render(
+    state: &mut state::State,
+    theme: &ui::Theme,
+    area: layout::Rect,
+    frame: &mut ratatui::Frame,
+) {
+    let (area, detail_area) = ui::split::areas(&state.split, area);
+
+    let title = format!(" Logins ({}) ", state.logins.logins.len());
+    let focus = state::SplitFocus::List;
+    let block = ui::split::block(&state.split, focus, theme, title);
+
+    // Drop the login IP column on narrow terminals
+    let breakpoint = ui::Breakpoint::of(area);
+    let all_columns = [0, 1, 2];
+    let columns: &[usize] = breakpoint.pick(&[0, 1], &all_columns, &all_columns);
+
+    // Construct the header row
+    let header = widgets::Row::new(ui::helpers::pick_columns(
+        ["Login on", "User", "Login IP"],
+        columns,
+    ))
+    .style(theme.style("table.header"));
+
+    // Construct a row for each login, newest first
+    let rows = state.logins.logins.iter().map(|login| {
+        let cells = [login.login_on.clone(), login.user_id.clone(), login_ip(login)];
+        widgets::Row::new(ui::helpers::pick_columns(cells, columns))
+    });
+
+    let user_width = breakpoint.pick(24, 36, 40);
+    let widths = ui::helpers::pick_columns(
+        [
+            layout::Constraint::Length(24),
+            layout::Constraint::Length(user_width),
+            layout::Constraint::Fill(1),
+        ],
+        columns,
+    );
+
+    let table = widgets::Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .highlight_style(theme.style("table.selected"));
+
+    frame.render_stateful_widget(table, area, &mut state.logins.table);
+
+    // Make the visible rows clickable
+    let len = state.logins.logins.len();
+    let table = &state.logins.table;
+    ui::helpers::register_table_rows(&mut state.mouse, area, table, len);
+
+    // Show the selected login along with what else is known about its user
+    let detail = state
+        .logins
+        .table
+        .selected()
+        .and_then(|row| state.logins.logins.get(row))
+        .map(|login| login_detail(state, login, theme));
+    ui::split::render_detail(state, theme, detail, detail_area, frame);
+}
+
+/// Build the detail pane lines for a login
+fn login_detail(
+    state: &state::State,
+    login: &domain::Login,
+    theme: &ui::Theme,
+) -> Vec<text::Line<'static>> {
+    let heading = |title| text::Line::styled(title, theme.style("table.header"));
+
+    // Did the logins watcher flag the login IP as new for the user
+    let is_flagged = state.security.events.iter().any(|event| {
+        event.user_id == login.user_id && Some(event.login_ip) == login.login_ip
+    });
+    let flagged = if is_flagged {
+        text::Span::styled("New login IP", theme.style("toast.warning"))
+    } else {
+        text::Span::styled("None", theme.style("text.dimmed"))
+    };
+    let mut security_line = ui::split::field("Security", String::new(), theme);
+    security_line.push_span(flagged);
+
+    let mut lines = vec![
+        ui::split::field("Login", login.id.clone(), theme),
+        ui::split::field("User", login.user_id.clone(), theme),
+        ui::split::field("Login on", login.login_on.clone(), theme),
+        ui::split::field("Login IP", login_ip(login), theme),
+        security_line,
+        text::Line::default(),
+        heading("Known IPs for the user"),
+    ];
+    lines.extend(
+        state
+            .security
+            .known_ips
+            .of_user(&login.user_id)
+            .map(|ip| text::Line::raw(ip.to_string())),
+    );
+
+    lines.push(text::Line::default());
+    lines.push(heading("Other recent logins by the user"));
+    let others: Vec<text::Line> = state
+        .logins
+        .by_same_user(login)
+        .map(|other| {
+            text::Line::raw(format!("{}  {}", other.login_on, login_ip(other)))
+        })
+        .collect();
+    if others.is_empty() {
+        lines.push(text::Line::styled("None", theme.style("text.dimmed")));
+    }
+    lines.extend(others);
+
+    lines
+}
+
+/// The login IP address, `-` if none was recorded
+fn login_ip(login: &domain::Login) -> String {
+    login
+        .login_ip
+        .map(|ip| ip.to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
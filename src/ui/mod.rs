@@ -3,11 +3,56 @@
 //! The UI modules
 //! ---
 
+/// TUI build and backend version layout
+pub mod about;
+
+/// Audit log viewer layout
+pub mod audit;
+
+/// Layout breakpoints for narrow, normal and wide terminals
+pub mod breakpoint;
+pub use breakpoint::Breakpoint;
+
+/// Self-contained ui components
+pub mod components;
+
 pub mod custom_widgets;
 
-pub mod footer;
+/// Text input form layout
+pub mod form;
 
 /// A collection of common ui helper functions
 pub mod helpers;
 
+/// Backend health panel layout
+pub mod health;
+
+/// Home screen layout
+pub mod home;
+
 pub mod layout;
+
+/// Login form layout
+pub mod login;
+
+/// Most recent logins layout
+pub mod logins;
+
+/// Account registration form layout
+pub mod register;
+
+/// Forgot and reset password form layout
+pub mod reset_password;
+
+/// Security events layout
+pub mod security;
+
+/// List and detail split pane container
+pub mod split;
+
+/// Screen tab bar layout
+pub mod tabs;
+
+/// Named styles used when rendering
+pub mod theme;
+pub use theme::Theme;
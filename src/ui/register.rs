@@ -0,0 +1,37 @@
+//-- ./src/ui/register.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Registration ui layout
+//!
+//! Contains the layout code for rendering the account registration form
+//! ---
+
+use ratatui::layout;
+
+use crate::{domain, state, ui};
+
+pub fn render(
+    state: &state::State,
+    theme: &ui::Theme,
+    area: layout::Rect,
+    frame: &mut ratatui::Frame,
+) {
+    let is_editing = state.app.mode == domain::AppMode::Input;
+
+    let help = if is_editing {
+        "Enter register | Tab next field | Esc done"
+    } else {
+        "m edit | l back to login"
+    };
+
+    ui::form::render(
+        &state.auth.register,
+        "Register",
+        help,
+        is_editing,
+        theme,
+        area,
+        frame,
+    );
+}
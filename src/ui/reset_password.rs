@@ -0,0 +1,43 @@
+//-- ./src/ui/reset_password.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Password reset ui layout
+//!
+//! Contains the layout code for rendering the forgot password form, to
+//! request a reset token, and the reset password form that uses the token
+//! ---
+
+use ratatui::layout;
+
+use crate::{domain, state, ui};
+
+pub fn render(
+    state: &state::State,
+    theme: &ui::Theme,
+    area: layout::Rect,
+    frame: &mut ratatui::Frame,
+) {
+    let is_editing = state.app.mode == domain::AppMode::Input;
+
+    let (form, title, help) = match state.navigation.current {
+        domain::Screen::ResetPassword => {
+            let help = if is_editing {
+                "Enter reset | Tab next field | Esc done"
+            } else {
+                "m edit or paste token | f request token | l back to login"
+            };
+            (&state.auth.reset_password, "Reset Password", help)
+        }
+        _ => {
+            let help = if is_editing {
+                "Enter send token | Esc done"
+            } else {
+                "m edit | t have a token | l back to login"
+            };
+            (&state.auth.forgot_password, "Forgot Password", help)
+        }
+    };
+
+    ui::form::render(form, title, help, is_editing, theme, area, frame);
+}
@@ -0,0 +1,95 @@
+//-- ./src/ui/security.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Security events ui layout
+//!
+//! Contains the layout code for rendering the security events raised by the
+//! logins watcher
+//! ---
+
+use ratatui::{layout, widgets};
+
+use crate::{domain, state, ui};
+
+pub fn render(
+    state: &mut state::State,
+    theme: &ui::Theme,
+    area: layout::Rect,
+    frame: &mut ratatui::Frame,
+) {
+    let title = format!(" Security events ({}) ", state.security.events.len());
+    let block = widgets::Block::bordered().title(title);
+
+    // Drop the kind and login on columns on narrow terminals
+    let breakpoint = ui::Breakpoint::of(area);
+    let all_columns = [0, 1, 2, 3, 4];
+    let columns: &[usize] = breakpoint.pick(&[0, 2, 3], &all_columns, &all_columns);
+
+    // Construct the header row
+    let header = widgets::Row::new(ui::helpers::pick_columns(
+        ["Detected", "Kind", "User", "Login IP", "Login on"],
+        columns,
+    ))
+    .style(theme.style("table.header"));
+
+    // Construct a row for each security event, newest first
+    let rows = state
+        .security
+        .events
+        .iter()
+        .map(|event| event_row(event, columns));
+
+    let user_width = breakpoint.pick(24, 36, 48);
+    let widths = ui::helpers::pick_columns(
+        [
+            layout::Constraint::Length(19),
+            layout::Constraint::Length(12),
+            layout::Constraint::Length(user_width),
+            layout::Constraint::Length(16),
+            layout::Constraint::Fill(1),
+        ],
+        columns,
+    );
+
+    let table = widgets::Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .highlight_style(theme.style("table.selected"));
+
+    frame.render_stateful_widget(table, area, &mut state.security.table);
+
+    // Make the visible rows clickable
+    let len = state.security.events.len();
+    ui::helpers::register_table_rows(&mut state.mouse, area, &state.security.table, len);
+}
+
+/// Build the table row for a security event
+fn event_row(
+    event: &domain::SecurityEvent,
+    columns: &[usize],
+) -> widgets::Row<'static> {
+    let cells = [
+        detected_at(event),
+        kind(event).to_string(),
+        event.user_id.clone(),
+        event.login_ip.to_string(),
+        event.login_on.clone(),
+    ];
+
+    widgets::Row::new(ui::helpers::pick_columns(cells, columns))
+}
+
+/// When the event was detected, in local time
+fn detected_at(event: &domain::SecurityEvent) -> String {
+    chrono::DateTime::<chrono::Local>::from(event.detected_at)
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+/// Describe the kind of security event
+fn kind(event: &domain::SecurityEvent) -> &'static str {
+    match event.kind {
+        domain::SecurityEventKind::NewLoginIp => "New login IP",
+    }
+}
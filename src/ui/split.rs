@@ -0,0 +1,101 @@
+//-- ./src/ui/split.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Split pane ui container
+//!
+//! Lays a list out beside the detail of its selected row, side by side on
+//! normal and wide terminals and stacked on narrow ones unless flipped. The
+//! pane with focus has its border highlighted.
+//! ---
+
+use ratatui::{layout, text, widgets};
+
+use crate::{state, ui};
+
+/// Split the area into the list and the detail pane areas
+pub fn areas(
+    split: &state::SplitState,
+    area: layout::Rect,
+) -> (layout::Rect, layout::Rect) {
+    let is_stacked = ui::Breakpoint::of(area).is_narrow() != split.is_flipped;
+    let direction = if is_stacked {
+        layout::Direction::Vertical
+    } else {
+        layout::Direction::Horizontal
+    };
+
+    let panes = layout::Layout::default()
+        .direction(direction)
+        .constraints([
+            layout::Constraint::Percentage(split.list_percent),
+            layout::Constraint::Fill(1),
+        ])
+        .split(area);
+    (panes[0], panes[1])
+}
+
+/// A bordered block for a pane, highlighted while it has focus
+pub fn block<'a>(
+    split: &state::SplitState,
+    focus: state::SplitFocus,
+    theme: &ui::Theme,
+    title: impl Into<text::Line<'a>>,
+) -> widgets::Block<'a> {
+    let block = widgets::Block::bordered().title(title);
+
+    if split.focus == focus {
+        block.border_style(theme.style("form.focus"))
+    } else {
+        block
+    }
+}
+
+/// A labelled line of the detail pane
+pub fn field<'a>(
+    label: &'a str,
+    value: String,
+    theme: &ui::Theme,
+) -> text::Line<'a> {
+    text::Line::from(vec![
+        text::Span::styled(format!("{label:<12}"), theme.style("table.header")),
+        text::Span::raw(value),
+    ])
+}
+
+/// Render the detail of the selected row, or a hint if no row is selected,
+/// keeping the scroll within the lines
+pub fn render_detail(
+    state: &mut state::State,
+    theme: &ui::Theme,
+    lines: Option<Vec<text::Line<'_>>>,
+    area: layout::Rect,
+    frame: &mut ratatui::Frame,
+) {
+    let block = block(&state.split, state::SplitFocus::Detail, theme, " Detail ");
+    let inner = block.inner(area);
+
+    let lines = lines.unwrap_or_else(|| {
+        let hint = text::Span::styled(
+            "Select a row to see its detail",
+            theme.style("text.dimmed"),
+        );
+        vec![text::Line::from(hint)]
+    });
+
+    // Stop scrolling once the last line is at the bottom of the pane
+    let max_scroll = lines.len().saturating_sub(inner.height as usize);
+    let scroll = (state.split.detail_scroll as usize).min(max_scroll) as u16;
+    state.split.detail_scroll = scroll;
+
+    let paragraph = widgets::Paragraph::new(lines)
+        .block(block)
+        .wrap(widgets::Wrap { trim: false })
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, area);
+
+    // Clicking the detail moves focus to it
+    if !state.split.is_detail_focused() {
+        state.mouse.register(area, state::MouseTarget::SplitDetail);
+    }
+}
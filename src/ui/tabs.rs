@@ -0,0 +1,76 @@
+//-- ./src/ui/tabs.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Tab bar ui layout
+//!
+//! Contains the layout code for rendering the numbered screen tabs above the
+//! body
+//! ---
+
+use ratatui::{layout, style, text, widgets};
+
+use crate::{domain, state, ui};
+
+pub fn render(
+    state: &mut state::State,
+    theme: &ui::Theme,
+    area: layout::Rect,
+    frame: &mut ratatui::Frame,
+) {
+    let is_logged_in = state.backend.access_token.is_some();
+    let tabs = domain::Screen::tabs(is_logged_in);
+
+    // Only name the current screen on narrow terminals, numbering the rest
+    let is_narrow = ui::Breakpoint::of(area).is_narrow();
+
+    // Number each tab for jumping to it, dimming tabs the role can not view
+    let titles: Vec<text::Line> = tabs
+        .iter()
+        .enumerate()
+        .map(|(index, screen)| {
+            let title = if is_narrow && *screen != state.navigation.current {
+                format!("{}", index + 1)
+            } else {
+                format!("{} {screen}", index + 1)
+            };
+            let is_permitted = state
+                .backend
+                .role
+                .is_permitted(&domain::Action::Navigate(*screen));
+
+            if is_permitted {
+                text::Line::from(title)
+            } else {
+                text::Line::from(title).style(theme.style("tab.disabled"))
+            }
+        })
+        .collect();
+
+    // Make each tab clickable, including the padding either side of the title
+    // and stepping over the divider
+    let mut x = area.x;
+    for (index, title) in titles.iter().enumerate() {
+        let width = (title.width() as u16 + 2).min(area.right().saturating_sub(x));
+        let tab_area = layout::Rect::new(x, area.y, width, 1);
+        state.mouse.register(tab_area, state::MouseTarget::Tab(index));
+        x = x.saturating_add(width + 1);
+    }
+
+    let selected = tabs
+        .iter()
+        .position(|screen| *screen == state.navigation.current);
+
+    let tabs = widgets::Tabs::new(titles)
+        .select(selected.unwrap_or_default())
+        .highlight_style(theme.style("tab.selected"));
+
+    // Only highlight a tab if the current screen is one
+    let tabs = if selected.is_some() {
+        tabs
+    } else {
+        tabs.highlight_style(style::Style::default())
+    };
+
+    frame.render_widget(tabs, area);
+}
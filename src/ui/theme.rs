@@ -0,0 +1,341 @@
+//-- ./src/ui/theme.rs
+
+// #![allow(unused)] // For beginning only.
+
+//! # Theme
+//!
+//! Named styles used when rendering, i.e. `status.online` or `toast.error`.
+//! A theme starts from a built-in dark, light or high-contrast theme, then
+//! applies the configured theme file and style overrides. If the `NO_COLOR`
+//! environment variable is set all colours are dropped and only modifiers
+//! are used.
+//!
+//! # Reference
+//!
+//! - [NO_COLOR](https://no-color.org/)
+//! ---
+
+use std::{collections::BTreeMap, env, fs, str::FromStr};
+
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::{config, domain, prelude::*};
+
+/// Every named style, a configured style outside this list is an error
+pub const STYLE_NAMES: [&str; 23] = [
+    "status.connecting",
+    "status.offline",
+    "status.online",
+    "status.logged_in",
+    "status.degraded",
+    "status.unauthorized",
+    "status.incompatible",
+    "mode.normal",
+    "mode.input",
+    "toast.error",
+    "toast.info",
+    "toast.notification",
+    "toast.success",
+    "toast.warning",
+    "tab.selected",
+    "tab.disabled",
+    "table.header",
+    "table.selected",
+    "table.dimmed",
+    "form.focus",
+    "form.error",
+    "text.dimmed",
+    "statistics",
+];
+
+/// Extra modifiers, keeping styles apart once `NO_COLOR` drops the colours
+const NO_COLOR_MODIFIERS: [(&str, Modifier); 8] = [
+    ("status.offline", Modifier::CROSSED_OUT),
+    ("status.degraded", Modifier::UNDERLINED),
+    ("status.incompatible", Modifier::REVERSED),
+    ("mode.input", Modifier::REVERSED),
+    ("toast.error", Modifier::REVERSED),
+    ("toast.warning", Modifier::UNDERLINED),
+    ("form.focus", Modifier::BOLD),
+    ("form.error", Modifier::ITALIC),
+];
+
+/// Resolved named styles
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    styles: BTreeMap<&'static str, Style>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// Build the theme from the config, failing on unknown theme names, style
+    /// names, colours or modifiers
+    pub fn new(config: &config::ThemeConfig) -> Result<Self> {
+        //-- 1. Read the custom theme file, if any
+        let file = match &config.file {
+            Some(path) => {
+                let contents = fs::read_to_string(path)?;
+                toml::from_str::<config::ThemeFile>(&contents).map_err(|error| {
+                    Error::Theme(format!("{}: {error}", path.display()))
+                })?
+            }
+            None => config::ThemeFile::default(),
+        };
+
+        //-- 2. Start from the built-in theme
+        let name = file.base.as_deref().unwrap_or(&config.name);
+        let mut theme = Self::built_in(name)?;
+
+        //-- 3. Apply the theme file, then the config overrides
+        for (name, style) in file.styles.iter().chain(&config.styles) {
+            theme.set(name, style)?;
+        }
+
+        //-- 4. Drop the colours if asked to
+        let no_color = env::var("NO_COLOR").is_ok_and(|value| !value.is_empty());
+        if no_color {
+            theme = theme.without_colour();
+        }
+
+        Ok(theme)
+    }
+
+    /// The built-in theme with the name
+    pub fn built_in(name: &str) -> Result<Self> {
+        match name {
+            "dark" => Ok(Self::dark()),
+            "light" => Ok(Self::light()),
+            "high-contrast" => Ok(Self::high_contrast()),
+            _ => Err(Error::Theme(format!("unknown theme `{name}`"))),
+        }
+    }
+
+    /// The named style, or the default style for an unknown name
+    pub fn style(&self, name: &str) -> Style {
+        self.styles.get(name).copied().unwrap_or_default()
+    }
+
+    /// The style for a kind of toast message
+    pub fn toast(&self, kind: &domain::ToastKind) -> Style {
+        match kind {
+            domain::ToastKind::Error => self.style("toast.error"),
+            domain::ToastKind::Info => self.style("toast.info"),
+            domain::ToastKind::Notification => self.style("toast.notification"),
+            domain::ToastKind::Success => self.style("toast.success"),
+            domain::ToastKind::Warning => self.style("toast.warning"),
+        }
+    }
+
+    /// Replace a named style with a configured style
+    fn set(&mut self, name: &str, config: &config::StyleConfig) -> Result<()> {
+        let Some(name) = STYLE_NAMES.iter().find(|known| **known == name) else {
+            return Err(Error::Theme(format!("unknown style `{name}`")));
+        };
+
+        let mut style = Style::default();
+        if let Some(fg) = &config.fg {
+            style = style.fg(parse_colour(fg)?);
+        }
+        if let Some(bg) = &config.bg {
+            style = style.bg(parse_colour(bg)?);
+        }
+        for modifier in &config.modifiers {
+            style = style.add_modifier(parse_modifier(modifier)?);
+        }
+
+        self.styles.insert(name, style);
+
+        Ok(())
+    }
+
+    /// Drop every colour, keeping the modifiers and adding the modifiers that
+    /// keep styles apart without colour
+    fn without_colour(mut self) -> Self {
+        for style in self.styles.values_mut() {
+            style.fg = None;
+            style.bg = None;
+        }
+        for (name, modifier) in NO_COLOR_MODIFIERS {
+            if let Some(style) = self.styles.get_mut(name) {
+                *style = style.add_modifier(modifier);
+            }
+        }
+        self
+    }
+
+    /// Build a theme from the named styles
+    fn from_styles(styles: [(&'static str, Style); 23]) -> Self {
+        Self {
+            styles: styles.into_iter().collect(),
+        }
+    }
+
+    /// Light text on a dark terminal background
+    pub fn dark() -> Self {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+
+        Self::from_styles([
+            ("status.connecting", bold.fg(Color::Gray)),
+            ("status.offline", bold.fg(Color::LightRed)),
+            ("status.online", bold.fg(Color::LightGreen)),
+            ("status.logged_in", bold.fg(Color::Green)),
+            ("status.degraded", bold.fg(Color::LightYellow)),
+            ("status.unauthorized", bold.fg(Color::LightMagenta)),
+            ("status.incompatible", bold.fg(Color::Black).bg(Color::LightRed)),
+            ("mode.normal", bold.fg(Color::White)),
+            ("mode.input", bold.fg(Color::LightGreen)),
+            ("toast.error", bold.fg(Color::Red)),
+            ("toast.info", bold.fg(Color::LightBlue)),
+            ("toast.notification", bold.fg(Color::White)),
+            ("toast.success", bold.fg(Color::LightGreen)),
+            ("toast.warning", bold.fg(Color::LightRed)),
+            ("tab.selected", bold.add_modifier(Modifier::REVERSED)),
+            ("tab.disabled", Style::default().add_modifier(Modifier::DIM)),
+            ("table.header", bold),
+            (
+                "table.selected",
+                Style::default().add_modifier(Modifier::REVERSED),
+            ),
+            ("table.dimmed", Style::default().add_modifier(Modifier::DIM)),
+            ("form.focus", Style::default().fg(Color::Yellow)),
+            ("form.error", Style::default().fg(Color::LightRed)),
+            ("text.dimmed", Style::default().add_modifier(Modifier::DIM)),
+            ("statistics", Style::default().add_modifier(Modifier::DIM)),
+        ])
+    }
+
+    /// Dark text on a light terminal background
+    pub fn light() -> Self {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+
+        Self::from_styles([
+            ("status.connecting", bold.fg(Color::DarkGray)),
+            ("status.offline", bold.fg(Color::Red)),
+            ("status.online", bold.fg(Color::Green)),
+            ("status.logged_in", bold.fg(Color::Blue)),
+            ("status.degraded", bold.fg(Color::Yellow)),
+            ("status.unauthorized", bold.fg(Color::Magenta)),
+            ("status.incompatible", bold.fg(Color::White).bg(Color::Red)),
+            ("mode.normal", bold.fg(Color::Black)),
+            ("mode.input", bold.fg(Color::Green)),
+            ("toast.error", bold.fg(Color::Red)),
+            ("toast.info", bold.fg(Color::Blue)),
+            ("toast.notification", bold.fg(Color::Black)),
+            ("toast.success", bold.fg(Color::Green)),
+            ("toast.warning", bold.fg(Color::Magenta)),
+            ("tab.selected", bold.add_modifier(Modifier::REVERSED)),
+            ("tab.disabled", Style::default().fg(Color::Gray)),
+            ("table.header", bold),
+            (
+                "table.selected",
+                Style::default().add_modifier(Modifier::REVERSED),
+            ),
+            ("table.dimmed", Style::default().fg(Color::Gray)),
+            ("form.focus", Style::default().fg(Color::Blue)),
+            ("form.error", Style::default().fg(Color::Red)),
+            ("text.dimmed", Style::default().fg(Color::DarkGray)),
+            ("statistics", Style::default().fg(Color::DarkGray)),
+        ])
+    }
+
+    /// Bold colours on solid backgrounds
+    pub fn high_contrast() -> Self {
+        let bold = Style::default().add_modifier(Modifier::BOLD);
+
+        Self::from_styles([
+            ("status.connecting", bold.fg(Color::Black).bg(Color::White)),
+            ("status.offline", bold.fg(Color::White).bg(Color::Red)),
+            ("status.online", bold.fg(Color::Black).bg(Color::Green)),
+            (
+                "status.logged_in",
+                bold.fg(Color::Black).bg(Color::LightGreen),
+            ),
+            ("status.degraded", bold.fg(Color::Black).bg(Color::Yellow)),
+            ("status.unauthorized", bold.fg(Color::White).bg(Color::Magenta)),
+            ("status.incompatible", bold.fg(Color::White).bg(Color::Red)),
+            ("mode.normal", bold.fg(Color::Black).bg(Color::White)),
+            ("mode.input", bold.fg(Color::Black).bg(Color::Yellow)),
+            ("toast.error", bold.fg(Color::White).bg(Color::Red)),
+            ("toast.info", bold.fg(Color::White).bg(Color::Blue)),
+            ("toast.notification", bold.fg(Color::Black).bg(Color::White)),
+            ("toast.success", bold.fg(Color::Black).bg(Color::Green)),
+            ("toast.warning", bold.fg(Color::Black).bg(Color::Yellow)),
+            ("tab.selected", bold.fg(Color::Black).bg(Color::Yellow)),
+            (
+                "tab.disabled",
+                Style::default().add_modifier(Modifier::CROSSED_OUT),
+            ),
+            ("table.header", bold.add_modifier(Modifier::UNDERLINED)),
+            ("table.selected", bold.fg(Color::Black).bg(Color::Yellow)),
+            (
+                "table.dimmed",
+                Style::default().add_modifier(Modifier::ITALIC),
+            ),
+            ("form.focus", bold.fg(Color::Yellow)),
+            ("form.error", bold.fg(Color::LightRed)),
+            ("text.dimmed", Style::default().fg(Color::Gray)),
+            ("statistics", Style::default().fg(Color::White)),
+        ])
+    }
+}
+
+/// Parse a colour name, index or `#rrggbb` value
+fn parse_colour(colour: &str) -> Result<Color> {
+    Color::from_str(colour)
+        .map_err(|_| Error::Theme(format!("unknown colour `{colour}`")))
+}
+
+/// Parse a modifier name
+fn parse_modifier(modifier: &str) -> Result<Modifier> {
+    match modifier.to_lowercase().as_str() {
+        "bold" => Ok(Modifier::BOLD),
+        "dim" => Ok(Modifier::DIM),
+        "italic" => Ok(Modifier::ITALIC),
+        "underlined" => Ok(Modifier::UNDERLINED),
+        "reversed" => Ok(Modifier::REVERSED),
+        "crossed_out" => Ok(Modifier::CROSSED_OUT),
+        "slow_blink" => Ok(Modifier::SLOW_BLINK),
+        _ => Err(Error::Theme(format!("unknown modifier `{modifier}`"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Bring current module into scope
+    use super::*;
+
+    #[test]
+    fn config_styles_override_the_built_in_theme() {
+        //-- Setup and Fixtures (Arrange)
+        let mut config = config::ThemeConfig::default();
+        let style = config::StyleConfig {
+            fg: Some("#ff0000".to_string()),
+            bg: None,
+            modifiers: vec!["underlined".to_string()],
+        };
+        config.styles.insert("toast.error".to_string(), style);
+        config
+            .styles
+            .insert("toast.eror".to_string(), config::StyleConfig::default());
+
+        //-- Execute Function (Act)
+        let mut theme = Theme::dark();
+        let known = theme.set("toast.error", &config.styles["toast.error"]);
+        let unknown = theme.set("toast.eror", &config.styles["toast.eror"]);
+
+        //-- Checks (Assertions)
+        assert!(known.is_ok());
+        assert!(unknown.is_err());
+        assert_eq!(
+            theme.style("toast.error"),
+            Style::default()
+                .fg(Color::Rgb(255, 0, 0))
+                .add_modifier(Modifier::UNDERLINED)
+        );
+    }
+}